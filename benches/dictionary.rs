@@ -0,0 +1,51 @@
+// 실행 중에 사전을 파싱한 HashMap(load_dictionary)과 빌드할 때 만든 정적 phf 맵(load_arc_dictionary) 비교
+// cargo bench --bench dictionary
+
+use std::collections::HashMap;
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use rust_web::{convert_spans, CharTable, ConvertOptions};
+
+const SAMPLE: &str = "大韓民國은 民主共和國이다. 大韓民國의 主權은 國民에게 있고, 모든 權力은 國民으로부터 나온다. \
+    獨立運動의 歷史와 六月 民主抗爭의 精神을 이어받아 自由民主的 基本秩序를 더욱 確固히 한다.";
+
+fn bench_load(c: &mut Criterion) {
+    let mut group = c.benchmark_group("load");
+    group.bench_function("hashmap (runtime parse)", |b| b.iter(|| black_box(rust_web::load_dictionary().unwrap())));
+    group.bench_function("phf (build time)", |b| b.iter(|| black_box(rust_web::load_arc_dictionary().unwrap())));
+    group.finish();
+}
+
+fn bench_lookup(c: &mut Criterion) {
+    let (char_map, _, _) = rust_web::load_dictionary().unwrap();
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    let chars: Vec<char> = SAMPLE.chars().collect();
+
+    let mut group = c.benchmark_group("lookup");
+    group.bench_function("hashmap", |b| b.iter(|| {
+        chars.iter().filter_map(|ch| char_map.get_char(black_box(ch))).count()
+    }));
+    group.bench_function("phf", |b| b.iter(|| {
+        chars.iter().filter_map(|ch| dic.char_dic.get_char(black_box(ch))).count()
+    }));
+    group.finish();
+}
+
+fn bench_convert(c: &mut Criterion) {
+    let (char_map, dueum_map, word_map) = rust_web::load_dictionary().unwrap();
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    let options = ConvertOptions::default();
+
+    let mut group = c.benchmark_group("convert");
+    group.bench_function("hashmap", |b| b.iter(|| {
+        convert_spans(black_box(SAMPLE), &char_map, &dueum_map, &word_map, &HashMap::<char, char>::new(), &HashMap::<char, char>::new(), &options)
+    }));
+    group.bench_function("phf", |b| b.iter(|| {
+        convert_spans(black_box(SAMPLE), dic.char_dic, dic.dueum_dic, dic.word_dic, dic.variant_dic, dic.traditional_dic, &options)
+    }));
+    group.finish();
+}
+
+criterion_group!(benches, bench_load, bench_lookup, bench_convert);
+criterion_main!(benches);
@@ -0,0 +1,158 @@
+// 빌드할 때 사전 원본(hanja_char.rs, dueum.rs, hanja_word.rs, hanja_info.rs, hanja_variant.rs, hanja_simplified.rs)을 읽어 정적 perfect-hash 맵을 만든다.
+// 서버가 시작할 때 28,000여 줄을 파싱하지 않아도 된다. phf도 key의 해시는 SipHash-1-3으로 계산하지만,
+// 빌드할 때 충돌이 없도록 배치해 두므로 찾을 때 충돌한 항목을 따라가며 비교하지 않는다.
+// 생성된 코드는 $OUT_DIR/dictionary_tables.rs에 저장되고 lib.rs에서 include!로 가져간다.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::Path;
+
+use sha2::{Digest, Sha256};
+
+#[allow(dead_code)]
+#[path = "src/dic_format.rs"]
+mod dic_format;
+
+use dic_format::{derived_readings, parse_line, EduLevel, Line, Reading, Record};
+
+const SOURCES: [&str; 7] = [
+    "src/hanja_char.rs", "src/dueum.rs", "src/hanja_word.rs", "src/hanja_info.rs", "src/hanja_variant.rs", "src/hanja_simplified.rs",
+    "src/dic_format.rs",
+];
+
+fn main() {
+    for source in SOURCES {
+        println!("cargo:rerun-if-changed={}", source);
+    }
+
+    let char_text = read_raw_string(SOURCES[0]);
+    let dueum_text = read_raw_string(SOURCES[1]);
+    let word_text = read_raw_string(SOURCES[2]);
+    let info_text = read_raw_string(SOURCES[3]);
+    let variant_text = read_raw_string(SOURCES[4]);
+    let simplified_text = read_raw_string(SOURCES[5]);
+
+    // 기본 읽기는 HANJA_BASIC을 따르고, HANJA_BASIC에 없는 한자만 HANJA_INFO의 첫 번째 읽기를 사용한다.
+    let mut chars = char_pairs(&char_text);
+    let info = records(&info_text);
+    for (hanja, record) in &info {
+        chars.entry(*hanja).or_insert(record.readings[0].hangul);
+    }
+    // 사전에 없는 호환용 한자와 부수 글자의 읽기 (load_dictionary와 같은 규칙)
+    let derived = derived_readings(|c| chars.get(&c).copied());
+    chars.extend(derived);
+
+    let mut out = String::new();
+    out.push_str(&format!("pub static DICTIONARY_VERSION: &str = {:?};\n\n",
+        dictionary_version(&[&char_text, &dueum_text, &word_text, &info_text, &variant_text, &simplified_text])));
+    out.push_str(&char_table("CHAR_TABLE", &chars));
+    out.push_str(&char_table("DUEUM_TABLE", &char_pairs(&dueum_text)));
+    out.push_str(&word_table("WORD_TABLE", &word_text));
+    out.push_str(&info_table("INFO_TABLE", &info));
+    out.push_str(&char_table("VARIANT_TABLE", &char_pairs(&variant_text)));
+    out.push_str(&char_table("TRADITIONAL_TABLE", &char_pairs(&simplified_text)));
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("dictionary_tables.rs");
+    fs::write(out_path, out).unwrap();
+}
+
+// 소스 파일에서 r#"..."# 안의 내용만 꺼낸다.
+fn read_raw_string(path: &str) -> String {
+    let source = fs::read_to_string(path).unwrap_or_else(|e| panic!("{}: {}", path, e));
+    let start = source.find("r#\"").unwrap_or_else(|| panic!("{}: r#\" not found", path)) + 3;
+    let end = source.rfind("\"#").unwrap_or_else(|| panic!("{}: \"# not found", path));
+    source[start..end].to_string()
+}
+
+// 런타임 로더(load_dictionary)와 같은 규칙으로 줄을 읽는다.
+// 잘못된 줄은 건너뛰고, 같은 key는 뒤의 값으로 덮어쓴다.
+fn lines(text: &str) -> impl Iterator<Item = Line> + '_ {
+    text.lines().filter_map(|line| parse_line(line).ok())
+}
+
+fn char_pairs(text: &str) -> BTreeMap<char, char> {
+    let mut map = BTreeMap::new();
+    for line in lines(text) {
+        match line {
+            Line::Pair(key, value) => {
+                map.insert(key.chars().next().unwrap(), value.chars().next().unwrap());
+            },
+            Line::Record(record) => {
+                map.insert(record.hanja, record.readings[0].hangul);
+            },
+            Line::Blank => {},
+        }
+    }
+    map
+}
+
+// 기존 형식의 줄은 읽기만 있는 한자 정보로 본다.
+fn records(text: &str) -> BTreeMap<char, Record> {
+    let mut map = BTreeMap::new();
+    for line in lines(text) {
+        let record = match line {
+            Line::Record(record) => record,
+            Line::Pair(key, value) => Record {
+                hanja: key.chars().next().unwrap(),
+                readings: vec![Reading { hangul: value.chars().next().unwrap(), rank: 1 }],
+                meaning: String::new(),
+                radical: None,
+                extra_strokes: None,
+                strokes: None,
+                grade: None,
+                edu: EduLevel::None,
+            },
+            Line::Blank => continue,
+        };
+        map.insert(record.hanja, record);
+    }
+    map
+}
+
+fn char_table(name: &str, entries: &BTreeMap<char, char>) -> String {
+    let mut map = phf_codegen::Map::<char>::new();
+    for (key, value) in entries {
+        map.entry(*key, format!("{:?}", value));
+    }
+    format!("pub static {}: phf::Map<char, char> = {};\n\n", name, map.build())
+}
+
+fn word_table(name: &str, text: &str) -> String {
+    let mut entries = BTreeMap::new();
+    for line in lines(text) {
+        if let Line::Pair(key, value) = line {
+            entries.insert(key, value);
+        }
+    }
+    let mut map = phf_codegen::Map::<&str>::new();
+    for (key, value) in &entries {
+        map.entry(key.as_str(), format!("{:?}", value));
+    }
+    format!("pub static {}: phf::Map<&'static str, &'static str> = {};\n\n", name, map.build())
+}
+
+fn info_table(name: &str, entries: &BTreeMap<char, Record>) -> String {
+    let mut map = phf_codegen::Map::<char>::new();
+    for (key, record) in entries {
+        let readings = record.readings.iter()
+            .map(|r| format!("crate::Reading {{ hangul: {:?}, rank: {} }}", r.hangul, r.rank))
+            .collect::<Vec<String>>()
+            .join(", ");
+        map.entry(*key, format!(
+            "crate::HanjaInfo {{ hanja: {:?}, readings: &[{}], meaning: {:?}, radical: {:?}, extra_strokes: {:?}, strokes: {:?}, grade: {:?}, edu: crate::EduLevel::{:?} }}",
+            record.hanja, readings, record.meaning, record.radical, record.extra_strokes, record.strokes,
+            record.grade.as_deref(), record.edu));
+    }
+    format!("pub static {}: phf::Map<char, crate::HanjaInfo> = {};\n\n", name, map.build())
+}
+
+// lib.rs의 digest_hex와 같은 방법으로 계산한다.
+fn dictionary_version(parts: &[&str]) -> String {
+    let mut hasher = Sha256::new();
+    for part in parts {
+        hasher.update((part.len() as u64).to_le_bytes());
+        hasher.update(part.as_bytes());
+    }
+    hasher.finalize()[..16].iter().map(|b| format!("{:02x}", b)).collect::<String>()
+}
@@ -17,9 +17,7 @@ rm -r "$DEPLOY_DIR"
 mkdir -p "$DEPLOY_DIR"
 cp -r ./target/release/rust_web "$DEPLOY_DIR"
 cp -r ./log4rs.yml "$DEPLOY_DIR"
-cp -r ./index.html "$DEPLOY_DIR"
-cp -r ./css "$DEPLOY_DIR"
-cp -r ./js "$DEPLOY_DIR"
+# index.html, css, js are embedded in the binary at compile time
 
 echo "Deployment completed successfully."
 
@@ -1,3 +1,12 @@
+// 서버가 돌려주는 오류 상태 코드별 안내 문구
+const ERROR_MESSAGES = {
+    401: 'API 키가 올바르지 않습니다.',
+    408: '요청 처리 시간이 초과되었습니다. 입력을 나누어 다시 시도해 주세요.',
+    413: '입력한 문자열이 너무 깁니다. 입력을 나누어 다시 시도해 주세요.',
+    429: '요청이 너무 많습니다. 잠시 후 다시 시도해 주세요.',
+    503: '서버가 혼잡합니다. 잠시 후 다시 시도해 주세요.',
+};
+
 async function convertToHangul() {
     const inputText = document.getElementById('inputText').value;
 
@@ -13,6 +22,11 @@ async function convertToHangul() {
 
         // 서버 응답 처리
         if (!response.ok) {
+            const message = ERROR_MESSAGES[response.status];
+            if (message) {
+                document.getElementById('outputText').value = message;
+                return;
+            }
             throw new Error('서버 요청에 실패했습니다.');
         }
 
@@ -24,4 +38,63 @@ async function convertToHangul() {
         console.error('오류 발생:', error);
         document.getElementById('outputText').value = '오류 발생: 변환에 실패했습니다.';
     }
-}
\ No newline at end of file
+}
+
+// EPUB, DOCX 파일을 올려서 변환한 파일을 같은 이름으로 내려받는다.
+async function convertDocument(file) {
+    const status = document.getElementById('documentStatus');
+    status.textContent = `${file.name} 변환 중...`;
+
+    const form = new FormData();
+    form.append('file', file);
+    try {
+        const response = await fetch('/document', {
+            method: 'POST',
+            body: form,
+        });
+
+        if (!response.ok) {
+            if (response.status === 413) {
+                status.textContent = '파일이 너무 큽니다.';
+            } else {
+                status.textContent = ERROR_MESSAGES[response.status] || await response.text();
+            }
+            return;
+        }
+
+        const blob = await response.blob();
+        const link = document.createElement('a');
+        link.href = URL.createObjectURL(blob);
+        link.download = file.name;
+        link.click();
+        setTimeout(() => URL.revokeObjectURL(link.href), 60000);
+        status.textContent = `${file.name} 변환 완료`;
+    } catch (error) {
+        console.error('오류 발생:', error);
+        status.textContent = '오류 발생: 문서 변환에 실패했습니다.';
+    }
+}
+
+// CSP가 인라인 스크립트(onclick 등)를 막으므로 이벤트는 여기서 연결한다.
+document.getElementById('convertButton').addEventListener('click', convertToHangul);
+
+const dropZone = document.getElementById('dropZone');
+const documentFile = document.getElementById('documentFile');
+dropZone.addEventListener('dragover', (event) => {
+    event.preventDefault();
+    dropZone.classList.add('dragover');
+});
+dropZone.addEventListener('dragleave', () => dropZone.classList.remove('dragover'));
+dropZone.addEventListener('drop', (event) => {
+    event.preventDefault();
+    dropZone.classList.remove('dragover');
+    if (event.dataTransfer.files.length > 0) {
+        convertDocument(event.dataTransfer.files[0]);
+    }
+});
+documentFile.addEventListener('change', () => {
+    if (documentFile.files.length > 0) {
+        convertDocument(documentFile.files[0]);
+        documentFile.value = '';
+    }
+});
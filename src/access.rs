@@ -0,0 +1,162 @@
+// API 키 인증과 토큰 버킷(token bucket) 방식의 요청 속도 제한
+
+use std::collections::HashMap;
+use std::num::NonZeroUsize;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use lru::LruCache;
+
+// 버킷이 이 개수에 이르면 가장 오래 사용되지 않은 버킷부터 지운다.
+const MAX_BUCKETS: usize = 10_000;
+
+// API 키 목록. key는 API 키, value는 키를 발급받은 클라이언트 이름이다.
+pub struct ApiKeys {
+    keys: HashMap<String, String>,
+}
+
+impl ApiKeys {
+    // API 키 파일은 ("클라이언트이름,API키\n") 형태의 여러 라인으로 구성되어 있다.
+    // 빈 줄과 '#'으로 시작하는 줄은 무시한다. 파일이 없으면 키가 없는 것으로 본다.
+    pub fn load(path: &str) -> ApiKeys {
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) => {
+                log::info!("API 키 파일을 읽지 않음({}): {}", path, e);
+                String::new()
+            }
+        };
+        ApiKeys::parse(&text)
+    }
+
+    pub fn parse(text: &str) -> ApiKeys {
+        let keys = text.lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| {
+                let parts: Vec<&str> = line.split(',').collect();
+                if parts.len() == 2 && !parts[1].trim().is_empty() {
+                    Some((parts[1].trim().to_string(), parts[0].trim().to_string()))
+                } else {
+                    log::warn!("잘못된 API 키 라인을 무시합니다: {}", line);
+                    None
+                }
+            })
+            .collect::<HashMap<String, String>>();
+        ApiKeys { keys }
+    }
+
+    // API 키에 해당하는 클라이언트 이름을 돌려준다.
+    pub fn client_name(&self, key: &str) -> Option<&str> {
+        self.keys.get(key).map(|name| name.as_str())
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+}
+
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+// 클라이언트(API 키 또는 IP) 별로 토큰 버킷을 하나씩 관리한다.
+// 버킷에는 최대 burst개의 토큰이 들어가고, 1분에 per_minute개씩 다시 채워진다.
+pub struct RateLimiter {
+    capacity: f64,
+    refill_per_sec: f64,
+    buckets: Mutex<LruCache<String, Bucket>>,
+}
+
+impl RateLimiter {
+    pub fn new(per_minute: u32, burst: u32) -> RateLimiter {
+        RateLimiter::with_max_clients(per_minute, burst, MAX_BUCKETS)
+    }
+
+    // 버킷을 최대 max_clients개까지 둔다. 넘으면 가장 오래 사용되지 않은 클라이언트의 버킷을 지운다.
+    pub fn with_max_clients(per_minute: u32, burst: u32, max_clients: usize) -> RateLimiter {
+        RateLimiter {
+            capacity: burst.max(1) as f64,
+            refill_per_sec: per_minute.max(1) as f64 / 60.0,
+            buckets: Mutex::new(LruCache::new(NonZeroUsize::new(max_clients).unwrap_or(NonZeroUsize::MIN))),
+        }
+    }
+
+    // 지금 버킷이 있는 클라이언트 수
+    pub fn len(&self) -> usize {
+        self.buckets.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // 토큰 하나를 사용한다. 토큰이 없으면 다음 토큰이 채워질 때까지 기다려야 하는 시간을 돌려준다.
+    pub fn check(&self, client: &str) -> Result<(), Duration> {
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        let bucket = buckets.get_or_insert_mut(client.to_string(), || Bucket {
+            tokens: self.capacity,
+            updated: now,
+        });
+        let elapsed = now.saturating_duration_since(bucket.updated).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+        bucket.updated = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            let wait = (1.0 - bucket.tokens) / self.refill_per_sec;
+            Err(Duration::from_secs_f64(wait))
+        }
+    }
+}
+
+// 요청을 거절한 이유
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Denied {
+    InvalidKey,              // 알 수 없는 API 키 (401)
+    TooManyRequests(Duration),  // 속도 제한. 다시 시도할 수 있을 때까지 기다려야 하는 시간 (429)
+}
+
+// 변환 요청의 API 키 인증과 속도 제한에 필요한 상태
+pub struct AccessControl {
+    pub api_keys: ApiKeys,
+    pub key_limiter: RateLimiter,   // API 키 별 제한
+    pub anon_limiter: RateLimiter,  // API 키가 없는 요청(브라우저 UI 등)과 알 수 없는 키로 보낸 요청의 IP 별 제한
+}
+
+impl AccessControl {
+    // authorization은 Authorization 헤더 값, ip는 요청을 보낸 주소이다.
+    // API 키가 있으면 키 별로, 없으면 IP 별로 요청 속도를 제한한다.
+    // 알 수 없는 키로 보낸 요청도 IP 별 제한에 포함하므로, 키를 계속 바꾸어 가며 맞춰 볼 수 없다.
+    pub fn check(&self, authorization: Option<&str>, ip: &str) -> Result<(), Denied> {
+        let Some(value) = authorization else {
+            return self.anon_limiter.check(ip).map_err(Denied::TooManyRequests);
+        };
+
+        let key = bearer_token(value).unwrap_or("");
+        if self.api_keys.client_name(key).is_none() {
+            self.anon_limiter.check(ip).map_err(Denied::TooManyRequests)?;
+            return Err(Denied::InvalidKey);
+        }
+        self.key_limiter.check(key).map_err(Denied::TooManyRequests)
+    }
+
+    // 운영용 API(/api/cache 등)는 API 키가 있어야 한다. 키가 없는 요청도 알 수 없는 키와 같이 처리한다.
+    pub fn check_key(&self, authorization: Option<&str>, ip: &str) -> Result<(), Denied> {
+        self.check(Some(authorization.unwrap_or("")), ip)
+    }
+}
+
+// Authorization 헤더 값에서 API 키를 꺼낸다. 인증 방식(Bearer)은 대소문자를 구분하지 않는다.
+fn bearer_token(value: &str) -> Option<&str> {
+    let (scheme, token) = value.trim().split_once(' ')?;
+    scheme.eq_ignore_ascii_case("Bearer").then(|| token.trim())
+}
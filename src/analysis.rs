@@ -0,0 +1,165 @@
+// 문서에 나온 한자 단어와 한자의 목록, 읽기, 횟수, 급수 (학습지 만들기용)
+// 변환과 같은 방법으로 한자 구간을 나눈다: 불규칙 변환 한자사전에 있는 단어는 그 단어로,
+// 사전에 없는 한자가 이어진 부분은 이어진 한자 전체를 한 단어로 본다.
+// 정규화(normalize)하면 호환용 한자와 이체자를 표준자로 모아서 센다.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{ConvertOptions, Dictionary, SpanKind};
+
+// 출력 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportFormat {
+    #[default]
+    Json,
+    Csv,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VocabKind {
+    Word,  // 한자 단어
+    Char,  // 한자 한 글자
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct VocabEntry {
+    pub kind: VocabKind,
+    pub hanja: String,
+    pub reading: String,
+    pub count: usize,
+    pub grade: Option<&'static str>,  // 단어는 가장 어려운 한자의 급수. 급수를 모르는 한자가 있으면 None
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Analysis {
+    pub total_words: usize,  // 중복을 포함한 단어 수
+    pub total_chars: usize,  // 중복을 포함한 한자 수
+    pub words: Vec<VocabEntry>,  // 많이 나온 순서, 같으면 먼저 나온 순서
+    pub chars: Vec<VocabEntry>,
+}
+
+// 한자능력검정시험 급수. 쉬운 급수부터
+const GRADES: [&str; 15] = ["8급", "7급II", "7급", "6급II", "6급", "5급II", "5급", "4급II", "4급", "3급II", "3급", "2급", "1급", "특급II", "특급"];
+
+impl Dictionary {
+    pub fn analyze(&self, input: &str, options: &ConvertOptions) -> Analysis {
+        let conversion = self.convert(input, options);
+        let mut words = Counter::default();
+        let mut chars = Counter::default();
+
+        // 1. 이어진 한자 구간을 단어로 나눈다.
+        //    사전 단어(Word)는 그대로, 그 사이에 이어진 글자(Char, Unknown)는 모아서 한 단어로 센다.
+        let mut run_hanja = String::new();
+        let mut run_reading = String::new();
+        for span in &conversion.spans {
+            if matches!(span.kind, SpanKind::Word | SpanKind::Rule | SpanKind::Text) {
+                words.add_run(&mut run_hanja, &mut run_reading);
+            }
+            match span.kind {
+                SpanKind::Word | SpanKind::Rule => words.add(span.normalized_source(), &span.reading),
+                SpanKind::Char | SpanKind::Unknown => {
+                    run_hanja.push_str(span.normalized_source());
+                    run_reading.push_str(&span.reading);
+                },
+                SpanKind::Text => {},
+            }
+        }
+        words.add_run(&mut run_hanja, &mut run_reading);
+
+        // 2. 한자 한 글자씩. 읽기는 두음법칙을 적용하지 않은 기본 읽기
+        for c in conversion.spans.iter().filter(|span| span.is_hanja()).flat_map(|span| span.normalized_source().chars()) {
+            let reading = self.char_dic.get(&c).copied().unwrap_or(c);
+            chars.add(&c.to_string(), &reading.to_string());
+        }
+
+        Analysis {
+            total_words: words.total,
+            total_chars: chars.total,
+            words: words.into_entries(VocabKind::Word, self),
+            chars: chars.into_entries(VocabKind::Char, self),
+        }
+    }
+
+    // 단어의 급수: 가장 어려운 한자의 급수
+    fn word_grade(&self, hanja: &str) -> Option<&'static str> {
+        let mut hardest: Option<(usize, &'static str)> = None;
+        for c in hanja.chars() {
+            let grade = self.info(c).and_then(|info| info.grade)?;
+            let rank = GRADES.iter().position(|g| *g == grade)?;
+            if hardest.is_none_or(|(r, _)| rank > r) {
+                hardest = Some((rank, grade));
+            }
+        }
+        hardest.map(|(_, grade)| grade)
+    }
+}
+
+impl Analysis {
+    // kind,hanja,reading,count,grade
+    pub fn to_csv(&self) -> String {
+        let mut buf = String::from("kind,hanja,reading,count,grade\r\n");
+        for entry in self.words.iter().chain(self.chars.iter()) {
+            let kind = match entry.kind {
+                VocabKind::Word => "word",
+                VocabKind::Char => "char",
+            };
+            buf.push_str(&format!("{},{},{},{},{}\r\n",
+                kind, csv_field(&entry.hanja), csv_field(&entry.reading), entry.count, csv_field(entry.grade.unwrap_or(""))));
+        }
+        buf
+    }
+}
+
+// (한자, 읽기)별 횟수. 처음 나온 순서를 기억한다.
+#[derive(Default)]
+struct Counter {
+    total: usize,
+    order: Vec<(String, String)>,
+    counts: HashMap<(String, String), usize>,
+}
+
+impl Counter {
+    fn add(&mut self, hanja: &str, reading: &str) {
+        self.total += 1;
+        let key = (hanja.to_string(), reading.to_string());
+        let count = self.counts.entry(key.clone()).or_insert(0);
+        if *count == 0 {
+            self.order.push(key);
+        }
+        *count += 1;
+    }
+
+    // 모아 둔 한자 구간을 한 단어로 세고 비운다. 한 글자는 단어로 세지 않는다.
+    fn add_run(&mut self, hanja: &mut String, reading: &mut String) {
+        if hanja.chars().count() > 1 {
+            self.add(hanja, reading);
+        }
+        hanja.clear();
+        reading.clear();
+    }
+
+    fn into_entries(self, kind: VocabKind, dic: &Dictionary) -> Vec<VocabEntry> {
+        let mut entries = self.order.into_iter()
+            .map(|(hanja, reading)| {
+                let grade = dic.word_grade(&hanja);
+                let count = self.counts[&(hanja.clone(), reading.clone())];
+                VocabEntry { kind, hanja, reading, count, grade }
+            })
+            .collect::<Vec<VocabEntry>>();
+        entries.sort_by_key(|entry| std::cmp::Reverse(entry.count));  // stable sort: 횟수가 같으면 먼저 나온 순서
+        entries
+    }
+}
+
+// 콤마, 따옴표, 줄바꿈이 있으면 따옴표로 감싼다.
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
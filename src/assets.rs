@@ -0,0 +1,73 @@
+// index.html, css, js 파일을 컴파일할 때 실행 파일에 넣어 두고 제공한다.
+// 실행 파일만 있으면 어느 디렉토리에서 실행하더라도 웹 페이지를 제공할 수 있다.
+// 개발 중에는 from_disk 옵션으로 디스크의 파일을 매번 읽어, 수정한 내용을 바로 확인할 수 있다.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use crate::{digest_hex, weak_etag};
+
+// (경로, Content-Type, 내용)
+const EMBEDDED: &[(&str, &str, &[u8])] = &[
+    ("index.html", "text/html; charset=utf-8", include_bytes!("../index.html")),
+    ("css/main.css", "text/css; charset=utf-8", include_bytes!("../css/main.css")),
+    ("js/main.js", "text/javascript; charset=utf-8", include_bytes!("../js/main.js")),
+];
+
+#[derive(Clone)]
+pub struct Asset {
+    pub content_type: &'static str,
+    pub body: Cow<'static, [u8]>,
+    pub etag: String,
+}
+
+pub struct Assets {
+    from_disk: bool,
+    embedded: HashMap<&'static str, Asset>,
+}
+
+impl Assets {
+    pub fn new(from_disk: bool) -> Assets {
+        let embedded = EMBEDDED.iter()
+            .map(|(path, content_type, body)| {
+                let asset = Asset {
+                    content_type,
+                    body: Cow::Borrowed(*body),
+                    etag: etag_for(body),
+                };
+                (*path, asset)
+            })
+            .collect::<HashMap<&'static str, Asset>>();
+        Assets { from_disk, embedded }
+    }
+
+    pub fn from_disk(&self) -> bool {
+        self.from_disk
+    }
+
+    // path는 "index.html", "css/main.css"와 같이 프로젝트 디렉토리 기준의 경로이다.
+    // 디스크에서 읽을 때에도 실행 파일에 들어 있는 목록의 파일만 제공한다.
+    pub async fn get(&self, path: &str) -> Option<Cow<'_, Asset>> {
+        let asset = self.embedded.get(path)?;
+        if !self.from_disk {
+            return Some(Cow::Borrowed(asset));
+        }
+
+        match tokio::fs::read(path).await {
+            Ok(body) => Some(Cow::Owned(Asset {
+                content_type: asset.content_type,
+                etag: etag_for(&body),
+                body: Cow::Owned(body),
+            })),
+            Err(e) => {
+                log::error!("Unable to read file {}: {}", path, e);
+                None
+            }
+        }
+    }
+}
+
+// 내용의 해시로 만든 ETag (W/와 큰따옴표 포함)
+fn etag_for(bytes: &[u8]) -> String {
+    weak_etag(&digest_hex(&[bytes]))
+}
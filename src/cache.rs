@@ -0,0 +1,142 @@
+// 변환 결과를 메모리에 보관하는 LRU 캐시
+// 같은 기사 제목이나 상투적인 문단이 반복해서 들어오므로, 한 번 변환한 결과를 다시 사용한다.
+// 캐시의 크기는 항목 수가 아니라 보관한 문자열의 byte 수로 제한한다.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use lru::LruCache;
+use serde::Serialize;
+
+use crate::pronunciation::Pronunciation;
+use crate::MappedChar;
+
+// 항목 하나를 보관하는 데 드는 대략적인 부가 비용(byte)
+const ENTRY_OVERHEAD: usize = 64;
+
+// 캐시에 보관하는 변환 결과
+#[derive(Debug, Clone)]
+pub struct CachedConversion {
+    pub converted_text: Option<String>,  // 변환할 한자가 없으면 None
+    pub mapped: Vec<MappedChar>,         // 변환하기 전에 다른 글자로 바꾼 한자
+    pub romanized: Option<String>,       // 로마자 표기를 요청하지 않았으면 None
+    pub pronunciations: Vec<Pronunciation>,  // 표준 발음을 요청하지 않았으면 비어 있다.
+}
+
+struct Entries {
+    lru: LruCache<String, CachedConversion>,
+    bytes: usize,
+    version: String,  // 현재 항목들을 만들 때 사용한 사전의 버전
+}
+
+pub struct ConversionCache {
+    capacity: usize,
+    entries: Mutex<Entries>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+#[derive(Serialize)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub entries: usize,
+    pub bytes: usize,
+    pub capacity: usize,
+}
+
+impl ConversionCache {
+    // capacity: 캐시가 보관할 수 있는 최대 byte 수
+    pub fn new(capacity: usize) -> ConversionCache {
+        ConversionCache {
+            capacity,
+            entries: Mutex::new(Entries {
+                lru: LruCache::unbounded(),
+                bytes: 0,
+                version: String::new(),
+            }),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    // key는 입력 문자열, 옵션, 사전 버전으로 만든 해시이다.
+    // 사전 버전이 바뀌었으면(사전을 다시 읽었으면) 이전 항목을 모두 지운다.
+    pub fn get(&self, version: &str, key: &str) -> Option<CachedConversion> {
+        let mut entries = self.lock();
+        if entries.version != version {
+            entries.reset(version);
+        }
+
+        match entries.lru.get(key) {
+            Some(value) => {
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                Some(value.clone())
+            },
+            None => {
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                None
+            }
+        }
+    }
+
+    // 변환 결과가 없는 경우(None)도 보관한다. 캐시 크기의 1/16보다 큰 결과는 보관하지 않는다.
+    pub fn insert(&self, version: &str, key: String, value: CachedConversion) {
+        let size = entry_size(&key, &value);
+        if size > self.capacity / 16 {
+            return;
+        }
+
+        let mut entries = self.lock();
+        if entries.version != version {
+            entries.reset(version);
+        }
+
+        if let Some(old) = entries.lru.put(key.clone(), value) {
+            entries.bytes -= entry_size(&key, &old);
+        }
+        entries.bytes += size;
+
+        while entries.bytes > self.capacity {
+            match entries.lru.pop_lru() {
+                Some((old_key, old_value)) => entries.bytes -= entry_size(&old_key, &old_value),
+                None => break,
+            }
+        }
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        let entries = self.lock();
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            entries: entries.lru.len(),
+            bytes: entries.bytes,
+            capacity: self.capacity,
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Entries> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Entries {
+    fn reset(&mut self, version: &str) {
+        if !self.version.is_empty() {
+            log::info!("사전 버전이 바뀌어 변환 캐시를 비웁니다: {} -> {}", self.version, version);
+        }
+        self.lru.clear();
+        self.bytes = 0;
+        self.version = version.to_string();
+    }
+}
+
+fn entry_size(key: &str, value: &CachedConversion) -> usize {
+    key.len()
+        + value.converted_text.as_ref().map_or(0, |v| v.len())
+        + value.romanized.as_ref().map_or(0, |v| v.len())
+        + value.pronunciations.iter().map(|p| std::mem::size_of::<Pronunciation>() + p.source.len() + p.reading.len() + p.pronunciation.len()).sum::<usize>()
+        + value.mapped.len() * std::mem::size_of::<MappedChar>()
+        + ENTRY_OVERHEAD
+}
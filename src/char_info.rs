@@ -0,0 +1,180 @@
+// 한자 한 글자의 정보: 읽기, 훈, 부수, 획수, 급수, 유니코드 블록, 호환용 한자와 이체자, 그 한자가 들어간 불규칙 변환 단어
+// GET /api/char/{c}에서 사용한다.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use serde::Serialize;
+use unicode_normalization::char::decompose_canonical;
+
+use crate::{is_chi, Dictionary, EduLevel, Reading};
+
+// 한자와 관련된 유니코드 블록 (시작, 끝, 이름)
+const CJK_BLOCKS: &[(u32, u32, &str)] = &[
+    (0x2E80, 0x2EFF, "CJK Radicals Supplement"),
+    (0x2F00, 0x2FDF, "Kangxi Radicals"),
+    (0x2FF0, 0x2FFF, "Ideographic Description Characters"),
+    (0x3000, 0x303F, "CJK Symbols and Punctuation"),
+    (0x31C0, 0x31EF, "CJK Strokes"),
+    (0x3200, 0x32FF, "Enclosed CJK Letters and Months"),
+    (0x3300, 0x33FF, "CJK Compatibility"),
+    (0x3400, 0x4DBF, "CJK Unified Ideographs Extension A"),
+    (0x4E00, 0x9FFF, "CJK Unified Ideographs"),
+    (0xF900, 0xFAFF, "CJK Compatibility Ideographs"),
+    (0xFE30, 0xFE4F, "CJK Compatibility Forms"),
+    (0x20000, 0x2A6DF, "CJK Unified Ideographs Extension B"),
+    (0x2A700, 0x2B73F, "CJK Unified Ideographs Extension C"),
+    (0x2B740, 0x2B81F, "CJK Unified Ideographs Extension D"),
+    (0x2B820, 0x2CEAF, "CJK Unified Ideographs Extension E"),
+    (0x2CEB0, 0x2EBEF, "CJK Unified Ideographs Extension F"),
+    (0x2EBF0, 0x2EE5F, "CJK Unified Ideographs Extension I"),
+    (0x2F800, 0x2FA1F, "CJK Compatibility Ideographs Supplement"),
+    (0x30000, 0x3134F, "CJK Unified Ideographs Extension G"),
+    (0x31350, 0x323AF, "CJK Unified Ideographs Extension H"),
+];
+
+// 호환용 한자가 있는 범위
+const COMPAT_RANGES: [(u32, u32); 2] = [(0xF900, 0xFAFF), (0x2F800, 0x2FA1F)];
+
+#[derive(Debug, Serialize)]
+pub struct CharDetail {
+    pub hanja: char,
+    pub codepoint: String,                // "U+6A02"
+    pub block: Option<&'static str>,      // 유니코드 블록 이름
+    pub reading: Option<char>,            // 변환할 때 쓰는 기본 읽기
+    pub readings: Vec<Reading>,           // 자주 쓰는 순서
+    pub meaning: Option<&'static str>,
+    pub radical: Option<char>,
+    pub extra_strokes: Option<u8>,
+    pub strokes: Option<u8>,
+    pub grade: Option<&'static str>,
+    pub edu: EduLevel,
+    pub has_info: bool,                   // 한자 정보 사전에 있는지. false이면 훈, 부수, 획수, 급수가 없다.
+    pub canonical: Option<RelatedChar>,   // 호환용 한자이면 대응하는 통합 한자
+    pub compatibility: Vec<RelatedChar>,  // 같은 글자의 호환용 한자
+    pub variants: Vec<RelatedChar>,       // 이체자 (이 글자가 이체자이면 표준자도 포함)
+    pub words: Vec<WordEntry>,            // 이 한자가 들어간 불규칙 변환 단어
+}
+
+// 관련 한자와 그 읽기
+#[derive(Debug, Serialize)]
+pub struct RelatedChar {
+    pub hanja: char,
+    pub codepoint: String,
+    pub reading: Option<char>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WordEntry {
+    pub hanja: &'static str,
+    pub hangul: &'static str,
+}
+
+impl Dictionary {
+    // 한자가 아니면 None
+    pub fn char_detail(&self, c: char) -> Option<CharDetail> {
+        if !is_chi(&c) {
+            return None;
+        }
+
+        // 호환용 한자에 훈, 부수 등의 정보가 없으면 통합 한자의 정보를 사용한다. 읽기는 호환용 한자의 것을 따른다.
+        let canonical = canonical_form(c);
+        let reading = self.char_dic.get(&c).copied();
+        let info = self.info(c).or_else(|| canonical.and_then(|u| self.info(u)));
+        let readings = match self.info(c) {
+            Some(info) => info.readings.to_vec(),
+            None => reading.map(|hangul| Reading { hangul, rank: 1 }).into_iter().collect(),
+        };
+
+        // 이체자: 이 글자가 이체자이면 표준자를, 표준자이면 그 이체자들을 모은다.
+        let standard = self.variant_dic.get(&c).copied().unwrap_or(c);
+        let mut variants = Vec::new();
+        if standard != c {
+            variants.push(standard);
+        }
+        for (variant, target) in self.variant_dic.entries() {
+            if *target == standard && *variant != c {
+                variants.push(*variant);
+            }
+        }
+        variants.sort();
+
+        // 사전 단어는 읽기를 나타내려고 호환용 한자로 적은 것도 있다(投降 U+FA09).
+        let base = canonical.unwrap_or(c);
+        let forms = compatibility_forms(base);
+        let mut words = self.word_dic.entries()
+            .filter(|(hanja, _)| hanja.chars().any(|w| w == c || w == base || forms.contains(&w)))
+            .map(|(hanja, hangul)| WordEntry { hanja, hangul })
+            .collect::<Vec<WordEntry>>();
+        words.sort_by_key(|word| word.hanja);
+
+        Some(CharDetail {
+            hanja: c,
+            codepoint: codepoint(c),
+            block: unicode_block(c),
+            reading,
+            readings,
+            meaning: info.map(|info| info.meaning).filter(|meaning| !meaning.is_empty()),
+            radical: info.and_then(|info| info.radical),
+            extra_strokes: info.and_then(|info| info.extra_strokes),
+            strokes: info.and_then(|info| info.strokes),
+            grade: info.and_then(|info| info.grade),
+            edu: info.map_or(EduLevel::None, |info| info.edu),
+            has_info: info.is_some(),
+            canonical: canonical.map(|u| self.related(u)),
+            compatibility: forms.iter().filter(|f| **f != c).map(|f| self.related(*f)).collect(),
+            variants: variants.into_iter().map(|v| self.related(v)).collect(),
+            words,
+        })
+    }
+
+    fn related(&self, c: char) -> RelatedChar {
+        RelatedChar {
+            hanja: c,
+            codepoint: codepoint(c),
+            reading: self.char_dic.get(&c).copied(),
+        }
+    }
+}
+
+pub fn unicode_block(c: char) -> Option<&'static str> {
+    let n = c as u32;
+    CJK_BLOCKS.iter()
+        .find(|(start, end, _)| (*start..=*end).contains(&n))
+        .map(|(_, _, name)| *name)
+}
+
+// 호환용 한자(U+F900~)이면 정규화(NFC)했을 때의 통합 한자. 통합 한자이거나 한자가 아니면(한글 등) None
+pub fn canonical_form(c: char) -> Option<char> {
+    if !is_chi(&c) {
+        return None;
+    }
+    let mut decomposed = Vec::new();
+    decompose_canonical(c, |d| decomposed.push(d));
+    match decomposed[..] {
+        [unified] if unified != c && is_chi(&unified) => Some(unified),
+        _ => None,
+    }
+}
+
+// 통합 한자에 대응하는 호환용 한자들 (코드 포인트 순서)
+// 한국 한자는 같은 글자의 다른 읽기를 호환용 한자로 구분하는 경우가 많다(樂 악, 樂 락).
+pub fn compatibility_forms(c: char) -> &'static [char] {
+    static INDEX: OnceLock<HashMap<char, Vec<char>>> = OnceLock::new();
+    let index = INDEX.get_or_init(|| {
+        let mut index: HashMap<char, Vec<char>> = HashMap::new();
+        for (start, end) in COMPAT_RANGES {
+            for compat in (start..=end).filter_map(char::from_u32) {
+                if let Some(unified) = canonical_form(compat) {
+                    index.entry(unified).or_default().push(compat);
+                }
+            }
+        }
+        index
+    });
+    index.get(&c).map_or(&[], |forms| forms.as_slice())
+}
+
+fn codepoint(c: char) -> String {
+    format!("U+{:04X}", c as u32)
+}
@@ -0,0 +1,281 @@
+// 명령행 도구
+//   rust_web convert [옵션] [파일...] : 웹 서버를 띄우지 않고 파일이나 표준 입력의 한자를 한글로 변환한다.
+//   rust_web lint-dic                : 내장 사전의 형식 오류, 중복 등을 검사한다.
+
+use std::collections::HashMap;
+use std::ffi::OsStr;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+use rust_web::html::HtmlOptions;
+use rust_web::lint::{self, Severity};
+use rust_web::romanize::Romanization;
+use rust_web::subtitle::{SubtitleFormat, DEFAULT_MAX_WIDTH};
+use rust_web::table::{TableFormat, TableOptions};
+use rust_web::{ConvertOptions, Dictionary, InputFormat, Normalize, OutputMode};
+
+const USAGE: &str = "사용법: rust_web convert [옵션] [파일...]
+
+파일을 지정하지 않거나 '-'를 지정하면 표준 입력을 변환합니다.
+
+옵션:
+  --mode <형식>          출력 형식: hangul(기본), hanja_hangul, hangul_hanja, ruby
+  --format <형식>        입력 형식: text(기본), html(텍스트 노드만 변환), markdown(본문만 변환), srt, vtt(자막의 대사만 변환),
+                         csv, tsv(지정한 열만 변환)
+  --skip-elements <목록> html에서 변환하지 않을 요소 (콤마로 구분, 기본: script,style,code,pre,kbd,samp,var,textarea,ruby)
+  --max-width <N>        srt, vtt에서 변환한 줄이 N칸(한글, 한자는 2칸)을 넘으면 경고합니다. (기본: 40)
+  --columns <목록>       csv, tsv에서 변환할 열의 이름 또는 번호(1부터) (콤마로 구분)
+  --append               csv, tsv에서 원래 열을 두고 변환한 값을 <열 이름>_hangul 열로 붙입니다.
+  --report               csv, tsv에서 행마다 사전에 없는 한자의 수를 unknown_hanja 열로 붙입니다.
+  --no-header            csv, tsv의 첫 행이 머리글이 아닙니다. 열은 번호로 지정합니다.
+  --romanize <표기법>    변환 결과 대신 한글 읽기의 로마자 표기를 출력합니다: rr(국어의 로마자 표기법), mr(매큔-라이샤워)
+                         text, html, markdown 형식에서만 사용할 수 있습니다.
+  --pronunciation        발음이 철자와 다른 한자 단어마다 뒤에 표준 발음을 붙입니다. (예: 독립[동닙])
+                         text, html, markdown 형식에서만 사용할 수 있습니다.
+  --no-dueum             두음법칙을 적용하지 않습니다.
+  --normalize <방식>     호환용 한자와 이체자 정규화: off(기본), match(단어를 찾을 때만), rewrite(출력하는 한자도 바꿈)
+  --traditional          간체자, 일본 신자체를 번체자로 바꾸어 변환합니다. 바꾼 한자는 표준 오류로 알려 줍니다.
+  --strict               사전에 없는 한자가 있으면 종료 코드 2로 끝납니다.
+  -o, --output-dir <DIR> 변환 결과를 표준 출력 대신 DIR에 같은 파일 이름으로 저장합니다.
+                         이름이 같은 입력 파일이 둘 이상이면 변환하지 않습니다.
+  -h, --help             이 도움말을 출력합니다.";
+
+// 종료 코드
+const EXIT_OK: i32 = 0;
+const EXIT_ERROR: i32 = 1;
+const EXIT_UNKNOWN_HANJA: i32 = 2;
+
+struct Args {
+    format: InputFormat,
+    options: ConvertOptions,
+    html: HtmlOptions,
+    max_width: usize,
+    table: TableOptions,
+    romanize: Option<Romanization>,
+    pronunciation: bool,
+    strict: bool,
+    output_dir: Option<PathBuf>,
+    inputs: Vec<String>,
+}
+
+// args는 "convert" 다음의 인자들이다. 종료 코드를 돌려준다.
+pub fn run(args: &[String]) -> i32 {
+    let args = match parse_args(args) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            println!("{}", USAGE);
+            return EXIT_OK;
+        },
+        Err(msg) => {
+            eprintln!("{}\n\n{}", msg, USAGE);
+            return EXIT_ERROR;
+        }
+    };
+
+    let dic = match rust_web::load_arc_dictionary() {
+        Ok(dic) => dic,
+        Err(e) => {
+            eprintln!("사전 로드 실패: {}", e);
+            return EXIT_ERROR;
+        }
+    };
+
+    if let Some(dir) = &args.output_dir {
+        // 다른 디렉토리의 같은 이름 파일(a/ch1.txt, b/ch1.txt)이 서로 덮어쓰지 않도록 변환하기 전에 확인한다.
+        let mut names: HashMap<&OsStr, &str> = HashMap::new();
+        for input in &args.inputs {
+            if let Some(first) = names.insert(output_name(input), input) {
+                eprintln!("{}, {}: 출력 파일 이름이 같습니다: {}", first, input, dir.join(output_name(input)).display());
+                return EXIT_ERROR;
+            }
+        }
+        if let Err(e) = std::fs::create_dir_all(dir) {
+            eprintln!("{}: {}", dir.display(), e);
+            return EXIT_ERROR;
+        }
+    }
+
+    let mut found_unknown = false;
+    for input in &args.inputs {
+        match convert_input(input, &args, &dic) {
+            Ok(unknown) => {
+                if !unknown.is_empty() {
+                    found_unknown = true;
+                    if args.strict {
+                        eprintln!("{}: 사전에 없는 한자: {}", input, unknown.iter().collect::<String>());
+                    }
+                }
+            },
+            Err(e) => {
+                eprintln!("{}: {}", input, e);
+                return EXIT_ERROR;
+            }
+        }
+    }
+
+    if args.strict && found_unknown { EXIT_UNKNOWN_HANJA } else { EXIT_OK }
+}
+
+// --output-dir에 저장할 파일 이름. 표준 입력은 stdin.txt
+fn output_name(input: &str) -> &OsStr {
+    if input == "-" {
+        "stdin.txt".as_ref()
+    } else {
+        Path::new(input).file_name().unwrap_or("output.txt".as_ref())
+    }
+}
+
+// 사전 검사 결과를 출력한다. 오류가 하나라도 있으면 종료 코드 1
+pub fn lint_dic() -> i32 {
+    let issues = lint::validate_dictionaries();
+    for issue in &issues {
+        println!("{}", issue);
+    }
+
+    let errors = issues.iter().filter(|issue| issue.severity == Severity::Error).count();
+    let warnings = issues.len() - errors;
+    println!("오류 {}개, 경고 {}개", errors, warnings);
+    if errors > 0 { EXIT_ERROR } else { EXIT_OK }
+}
+
+// 입력 하나를 변환해서 출력하고, 사전에 없는 한자를 돌려준다.
+fn convert_input(input: &str, args: &Args, dic: &Dictionary) -> std::io::Result<Vec<char>> {
+    let text = if input == "-" {
+        let mut text = String::new();
+        std::io::stdin().read_to_string(&mut text)?;
+        text
+    } else {
+        std::fs::read_to_string(input)?
+    };
+
+    // 변환 결과, 사전에 없는 한자, 바꾼 한자
+    let (converted, unknown, mapped) = match args.format {
+        InputFormat::Srt | InputFormat::Vtt => {
+            let format = if args.format == InputFormat::Srt { SubtitleFormat::Srt } else { SubtitleFormat::Vtt };
+            let result = dic.convert_subtitle(&text, format, &args.options, args.max_width)
+                .map_err(|msg| std::io::Error::new(std::io::ErrorKind::InvalidInput, msg))?;
+            for warning in &result.warnings {
+                eprintln!("{}: {}번째 자막 {}번째 줄이 {}칸으로 최대 너비 {}칸을 넘습니다: {}",
+                    input, warning.cue, warning.line, warning.width, warning.max_width, warning.text);
+            }
+            (result.subtitle, result.unknown, result.mapped)
+        },
+        InputFormat::Csv | InputFormat::Tsv => {
+            let format = if args.format == InputFormat::Csv { TableFormat::Csv } else { TableFormat::Tsv };
+            let result = dic.convert_table(&text, format, &args.table, &args.options)
+                .map_err(|msg| std::io::Error::new(std::io::ErrorKind::InvalidInput, msg))?;
+            (result.table, result.unknown, result.mapped)
+        },
+        _ => {
+            let conversion = match args.format {
+                InputFormat::Html => dic.convert_html(&text, &args.options, &args.html),
+                InputFormat::Markdown => dic.convert_markdown(&text, &args.options),
+                _ => dic.convert(&text, &args.options),
+            };
+            let converted = match args.romanize {
+                Some(system) => conversion.romanize(system),
+                None if args.pronunciation => conversion.render_pronounced(args.options.mode),
+                None => conversion.render(args.options.mode),
+            };
+            (converted, conversion.unknown(), conversion.mapped())
+        },
+    };
+    if args.options.traditional {
+        let mapped = mapped.iter().map(|m| format!("{}→{}", m.from, m.to)).collect::<Vec<String>>();
+        if !mapped.is_empty() {
+            eprintln!("{}: 바꾼 한자: {}", input, mapped.join(" "));
+        }
+    }
+
+    match &args.output_dir {
+        Some(dir) => {
+            std::fs::write(dir.join(output_name(input)), converted)?;
+        },
+        None => {
+            let mut stdout = std::io::stdout().lock();
+            stdout.write_all(converted.as_bytes())?;
+            stdout.flush()?;
+        }
+    }
+    Ok(unknown)
+}
+
+// 도움말을 요청하면 Ok(None)을 돌려준다.
+fn parse_args(args: &[String]) -> Result<Option<Args>, String> {
+    let mut parsed = Args {
+        format: InputFormat::Text,
+        options: ConvertOptions::default(),
+        html: HtmlOptions::default(),
+        max_width: DEFAULT_MAX_WIDTH,
+        table: TableOptions::default(),
+        romanize: None,
+        pronunciation: false,
+        strict: false,
+        output_dir: None,
+        inputs: Vec::new(),
+    };
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "--mode" => {
+                let value = iter.next().ok_or("--mode 다음에 출력 형식이 필요합니다.")?;
+                parsed.options.mode = OutputMode::parse(value)
+                    .ok_or(format!("알 수 없는 출력 형식: {}", value))?;
+            },
+            "--format" => {
+                let value = iter.next().ok_or("--format 다음에 입력 형식이 필요합니다.")?;
+                parsed.format = InputFormat::parse(value)
+                    .ok_or(format!("알 수 없는 입력 형식: {}", value))?;
+            },
+            "--skip-elements" => {
+                let value = iter.next().ok_or("--skip-elements 다음에 요소 목록이 필요합니다.")?;
+                parsed.html.skip_elements = value.split(',').map(|name| name.trim().to_string()).filter(|name| !name.is_empty()).collect();
+            },
+            "--max-width" => {
+                let value = iter.next().ok_or("--max-width 다음에 너비가 필요합니다.")?;
+                parsed.max_width = value.parse().map_err(|_| format!("잘못된 너비: {}", value))?;
+            },
+            "--columns" => {
+                let value = iter.next().ok_or("--columns 다음에 열 목록이 필요합니다.")?;
+                parsed.table.columns = value.split(',').map(|column| column.trim().to_string()).filter(|column| !column.is_empty()).collect();
+            },
+            "--append" => parsed.table.append = true,
+            "--report" => parsed.table.report = true,
+            "--no-header" => parsed.table.header = false,
+            "--romanize" => {
+                let value = iter.next().ok_or("--romanize 다음에 표기법이 필요합니다.")?;
+                parsed.romanize = Some(Romanization::parse(value)
+                    .ok_or(format!("알 수 없는 로마자 표기법: {}", value))?);
+            },
+            "--pronunciation" => parsed.pronunciation = true,
+            "--no-dueum" => parsed.options.dueum = false,
+            "--normalize" => {
+                let value = iter.next().ok_or("--normalize 다음에 정규화 방식이 필요합니다.")?;
+                parsed.options.normalize = Normalize::parse(value)
+                    .ok_or(format!("알 수 없는 정규화 방식: {}", value))?;
+            },
+            "--traditional" => parsed.options.traditional = true,
+            "--strict" => parsed.strict = true,
+            "-o" | "--output-dir" => {
+                let value = iter.next().ok_or("--output-dir 다음에 디렉토리가 필요합니다.")?;
+                parsed.output_dir = Some(PathBuf::from(value));
+            },
+            "-" => parsed.inputs.push(arg.clone()),
+            _ if arg.starts_with('-') => return Err(format!("알 수 없는 옵션: {}", arg)),
+            _ => parsed.inputs.push(arg.clone()),
+        }
+    }
+
+    if (parsed.romanize.is_some() || parsed.pronunciation) && !matches!(parsed.format, InputFormat::Text | InputFormat::Html | InputFormat::Markdown) {
+        return Err("--romanize, --pronunciation은 text, html, markdown 형식에서만 사용할 수 있습니다.".to_string());
+    }
+    if parsed.romanize.is_some() && parsed.pronunciation {
+        return Err("--romanize와 --pronunciation은 함께 사용할 수 없습니다.".to_string());
+    }
+    if parsed.inputs.is_empty() {
+        parsed.inputs.push("-".to_string());
+    }
+    Ok(Some(parsed))
+}
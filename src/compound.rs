@@ -0,0 +1,95 @@
+// 한자 복합어의 읽기 규칙
+// 불규칙 변환 한자사전(HANJA_SPECIAL)에 단어마다 읽기를 적는 대신, 규칙과 그 규칙을 따르는 단어만 적어 두고
+// 읽기는 글자마다의 읽기(HANJA_BASIC, 두음법칙)에 규칙을 적용해서 만든다.
+// 규칙으로 변환한 단어는 SpanKind::Rule 구간이 되고, 구간의 rule에 적용한 규칙이 들어 있다.
+//   - 사이시옷 (한글 맞춤법 제30항): 두 음절로 된 한자어 중 여섯 단어만 앞 음절에 'ㅅ'을 받친다. 庫間 고간 -> 곳간
+//   - 받침 탈락 (속음, 제52항): 끝 음절 앞 음절의 받침이 떨어진다. 六月 육월 -> 유월, 初八日 초팔일 -> 초파일
+//   - 활음조 (속음, 제52항): 첫 음절이 아닌 음절의 초성 'ㄴ'이 'ㄹ'로 바뀐다. 困難 곤난 -> 곤란, 許諾 허낙 -> 허락
+// 불규칙 변환 한자사전(word_dic)과 같이 이어진 한자의 남은 부분 전체가 규칙 단어일 때 적용한다. (總回數 -> 총횟수)
+// 사전에 같은 단어가 있으면 사전을 따르므로, 이어진 한자 전체가 다르게 읽히는 단어는 사전에 적는다. (十六月 -> 십육월)
+
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CompoundRule {
+    Saisiot,    // 사이시옷
+    DropFinal,  // 받침 탈락
+    Euphony,    // 활음조
+}
+
+// 규칙을 따르는 단어
+pub const COMPOUND_WORDS: &[(&str, CompoundRule)] = &[
+    ("庫間", CompoundRule::Saisiot),
+    ("貰房", CompoundRule::Saisiot),
+    ("數字", CompoundRule::Saisiot),
+    ("車間", CompoundRule::Saisiot),
+    ("退間", CompoundRule::Saisiot),
+    ("回數", CompoundRule::Saisiot),
+    ("六月", CompoundRule::DropFinal),
+    ("十月", CompoundRule::DropFinal),
+    ("初八日", CompoundRule::DropFinal),
+    ("木瓜", CompoundRule::DropFinal),
+    ("困難", CompoundRule::Euphony),
+    ("論難", CompoundRule::Euphony),
+    ("許諾", CompoundRule::Euphony),
+    ("受諾", CompoundRule::Euphony),
+    ("快諾", CompoundRule::Euphony),
+];
+
+const HANGUL_START: u32 = 0xAC00;
+const HANGUL_END: u32 = 0xD7A3;
+const FINAL_S: u32 = 19;    // 받침 'ㅅ'
+const INITIAL_N: u32 = 2;   // 초성 'ㄴ'
+const INITIAL_R: u32 = 5;   // 초성 'ㄹ'
+
+impl CompoundRule {
+    // 글자마다의 읽기에 규칙을 적용한다.
+    pub fn apply(&self, readings: &mut [char]) {
+        let len = readings.len();
+        match self {
+            CompoundRule::Saisiot => {
+                if let Some(first) = readings.first_mut() {
+                    *first = with_final(*first, FINAL_S);
+                }
+            },
+            CompoundRule::DropFinal => {
+                if len >= 2 {
+                    readings[len - 2] = with_final(readings[len - 2], 0);
+                }
+            },
+            CompoundRule::Euphony => {
+                for c in readings.iter_mut().skip(1) {
+                    if initial(*c) == Some(INITIAL_N) {
+                        *c = with_initial(*c, INITIAL_R);
+                    }
+                }
+            },
+        }
+    }
+}
+
+// 이어진 한자 전체(folded)가 규칙 단어이면 그 규칙
+pub(crate) fn find(folded: &str) -> Option<CompoundRule> {
+    COMPOUND_WORDS.iter()
+        .find(|(word, _)| *word == folded)
+        .map(|(_, rule)| *rule)
+}
+
+fn syllable(c: char) -> Option<u32> {
+    let n = c as u32;
+    (HANGUL_START..=HANGUL_END).contains(&n).then(|| n - HANGUL_START)
+}
+
+fn initial(c: char) -> Option<u32> {
+    syllable(c).map(|n| n / 588)
+}
+
+// 한글 음절이 아니면 그대로 둔다.
+fn with_final(c: char, final_index: u32) -> char {
+    syllable(c).and_then(|n| char::from_u32(HANGUL_START + n - n % 28 + final_index)).unwrap_or(c)
+}
+
+fn with_initial(c: char, initial_index: u32) -> char {
+    syllable(c).and_then(|n| char::from_u32(HANGUL_START + initial_index * 588 + n % 588)).unwrap_or(c)
+}
@@ -0,0 +1,277 @@
+// 변환 결과를 구간(span) 단위로 표현하고, 출력 형식에 맞추어 문자열로 만든다.
+
+use serde::{Deserialize, Serialize};
+
+use crate::compound::CompoundRule;
+
+// 출력 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OutputMode {
+    #[default]
+    Hangul,        // 대한민국
+    HanjaHangul,   // 大韓民國(대한민국)
+    HangulHanja,   // 대한민국(大韓民國)
+    Ruby,          // <ruby>大韓民國<rt>대한민국</rt></ruby>
+}
+
+impl OutputMode {
+    pub fn parse(s: &str) -> Option<OutputMode> {
+        match s {
+            "hangul" => Some(OutputMode::Hangul),
+            "hanja_hangul" => Some(OutputMode::HanjaHangul),
+            "hangul_hanja" => Some(OutputMode::HangulHanja),
+            "ruby" => Some(OutputMode::Ruby),
+            _ => None,
+        }
+    }
+}
+
+// 입력 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InputFormat {
+    #[default]
+    Text,  // 문자열 전체를 변환한다.
+    Html,  // 텍스트 노드만 변환한다. (html.rs 참조)
+    Markdown,  // 본문 글자만 변환한다. (markdown.rs 참조)
+    Srt,       // 자막의 대사만 변환한다. (subtitle.rs 참조)
+    Vtt,
+    Csv,       // 지정한 열만 변환한다. (table.rs 참조)
+    Tsv,
+}
+
+impl InputFormat {
+    pub fn parse(s: &str) -> Option<InputFormat> {
+        match s {
+            "text" => Some(InputFormat::Text),
+            "html" => Some(InputFormat::Html),
+            "markdown" | "md" => Some(InputFormat::Markdown),
+            "srt" => Some(InputFormat::Srt),
+            "vtt" => Some(InputFormat::Vtt),
+            "csv" => Some(InputFormat::Csv),
+            "tsv" => Some(InputFormat::Tsv),
+            _ => None,
+        }
+    }
+}
+
+// 호환용 한자(U+F900~)와 이체자 정규화
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Normalize {
+    #[default]
+    Off,      // 입력한 글자 그대로 사전에서 찾는다.
+    Match,    // 정규화한 글자로 불규칙 변환 단어를 찾는다. 출력하는 한자는 입력 그대로
+    Rewrite,  // Match와 같이 찾고, 출력하는 한자도 정규화한 글자로 바꾼다.
+}
+
+impl Normalize {
+    pub fn parse(s: &str) -> Option<Normalize> {
+        match s {
+            "off" => Some(Normalize::Off),
+            "match" => Some(Normalize::Match),
+            "rewrite" => Some(Normalize::Rewrite),
+            _ => None,
+        }
+    }
+}
+
+// 변환 옵션. API 요청의 JSON 필드와 CLI 옵션이 같은 이름을 사용한다.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConvertOptions {
+    pub mode: OutputMode,
+    pub dueum: bool,  // 두음법칙 적용 여부
+    pub normalize: Normalize,
+    pub traditional: bool,  // 간체자, 신자체를 번체자로 바꾸어 변환할지
+}
+
+impl Default for ConvertOptions {
+    fn default() -> ConvertOptions {
+        ConvertOptions {
+            mode: OutputMode::Hangul,
+            dueum: true,
+            normalize: Normalize::Off,
+            traditional: false,
+        }
+    }
+}
+
+impl ConvertOptions {
+    // 캐시 key, ETag 계산에 사용하는 옵션의 문자열 표현
+    pub fn key(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SpanKind {
+    Text,     // 한자가 아닌 문자열. 그대로 출력한다.
+    Word,     // 불규칙 변환 한자사전(word_dic)에서 찾은 단어
+    Rule,     // 복합어 읽기 규칙(compound.rs)으로 읽은 단어. span.rule에 적용한 규칙이 있다.
+    Char,     // 기본한자 사전(char_dic)에서 찾은 한자 한 글자
+    Unknown,  // 사전에 없는 한자 한 글자. 그대로 출력한다.
+}
+
+// 입력 문자열의 한 구간과 그 읽기
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Span {
+    pub start: usize,  // 입력 문자열에서의 시작 byte 위치
+    pub end: usize,    // 입력 문자열에서의 끝 byte 위치
+    pub source: String,
+    pub reading: String,
+    pub kind: SpanKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub normalized: Option<String>,  // 번체자로 바꾸거나 정규화한 한자. 바꾸지 않았거나 source와 같으면 None
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rule: Option<CompoundRule>,  // SpanKind::Rule 구간에 적용한 규칙
+}
+
+impl Span {
+    pub fn is_hanja(&self) -> bool {
+        self.kind != SpanKind::Text
+    }
+
+    // 정규화한 한자가 있으면 그 한자, 없으면 입력 그대로
+    pub fn normalized_source(&self) -> &str {
+        self.normalized.as_deref().unwrap_or(&self.source)
+    }
+}
+
+// 변환하기 전에 다른 글자로 바꾼 한자 (간체자 -> 번체자, 호환용 한자 -> 통합 한자 등)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct MappedChar {
+    pub from: char,
+    pub to: char,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Conversion {
+    pub spans: Vec<Span>,
+    pub rewrite: bool,  // 출력할 때 한자를 정규화한 글자로 바꿀지 (Normalize::Rewrite)
+}
+
+impl Conversion {
+    // 사전에서 찾아 변환한 한자가 하나라도 있는지
+    pub fn is_converted(&self) -> bool {
+        self.spans.iter().any(|span| matches!(span.kind, SpanKind::Word | SpanKind::Rule | SpanKind::Char))
+    }
+
+    // 사전에 없는 한자 (중복 제거, 처음 나온 순서)
+    pub fn unknown(&self) -> Vec<char> {
+        let mut unknown: Vec<char> = Vec::new();
+        for span in self.spans.iter().filter(|span| span.kind == SpanKind::Unknown) {
+            for c in span.source.chars() {
+                if !unknown.contains(&c) {
+                    unknown.push(c);
+                }
+            }
+        }
+        unknown
+    }
+
+    // 다른 글자로 바꾼 한자 (중복 제거, 처음 나온 순서)
+    pub fn mapped(&self) -> Vec<MappedChar> {
+        let mut mapped: Vec<MappedChar> = Vec::new();
+        for span in &self.spans {
+            let Some(normalized) = &span.normalized else { continue };
+            for (from, to) in span.source.chars().zip(normalized.chars()) {
+                let m = MappedChar { from, to };
+                if from != to && !mapped.contains(&m) {
+                    mapped.push(m);
+                }
+            }
+        }
+        mapped
+    }
+
+    // 출력 형식에 맞추어 문자열을 만든다.
+    pub fn render(&self, mode: OutputMode) -> String {
+        self.render_pieces(mode).into_iter().map(|piece| piece.text).collect()
+    }
+
+    // 입력의 각 부분과 그 부분을 출력한 문자열. 입력 순서대로 입력 전체를 덮는다.
+    // 괄호 형식은 이어진 한자 구간을 하나로 묶어 "大韓民國(대한민국)"처럼 출력하고,
+    // ruby 형식은 단어/글자 단위로 "<ruby>大<rt>대</rt></ruby>"처럼 출력한다.
+    pub fn render_pieces(&self, mode: OutputMode) -> Vec<Piece> {
+        let hanja = |span: &Span| if self.rewrite { span.normalized_source().to_string() } else { span.source.clone() };
+        let mut pieces = Vec::new();
+        let mut i = 0;
+        while i < self.spans.len() {
+            let span = &self.spans[i];
+            if !span.is_hanja() {
+                pieces.push(Piece { start: span.start, end: span.end, text: span.source.clone() });
+                i += 1;
+                continue;
+            }
+
+            let text = match mode {
+                OutputMode::Hangul => span.reading.clone(),
+                OutputMode::Ruby => format!("<ruby>{}<rt>{}</rt></ruby>", hanja(span), span.reading),
+                OutputMode::HanjaHangul | OutputMode::HangulHanja => {
+                    let start = span.start;
+                    let mut source = String::new();
+                    let mut reading = String::new();
+                    while i < self.spans.len() && self.spans[i].is_hanja() {
+                        source.push_str(&hanja(&self.spans[i]));
+                        reading.push_str(&self.spans[i].reading);
+                        i += 1;
+                    }
+                    let text = if mode == OutputMode::HanjaHangul {
+                        format!("{}({})", source, reading)
+                    } else {
+                        format!("{}({})", reading, source)
+                    };
+                    pieces.push(Piece { start, end: self.spans[i - 1].end, text });
+                    continue;
+                },
+            };
+            pieces.push(Piece { start: span.start, end: span.end, text });
+            i += 1;
+        }
+        pieces
+    }
+}
+
+// 출력의 한 부분. input[start..end]를 text로 출력한다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Piece {
+    pub start: usize,
+    pub end: usize,
+    pub text: String,
+}
+
+// 변환하지 않는 부분(HTML 태그 등)을 Text 구간으로 붙인다. 앞의 Text 구간과 이어지면 합친다.
+pub(crate) fn push_plain(spans: &mut Vec<Span>, input: &str, start: usize, end: usize) {
+    if start >= end {
+        return;
+    }
+    let s = &input[start..end];
+    match spans.last_mut() {
+        Some(last) if last.kind == SpanKind::Text && last.end == start => {
+            last.source.push_str(s);
+            last.reading.push_str(s);
+            last.end = end;
+        },
+        _ => spans.push(Span {
+            start,
+            end,
+            source: s.to_string(),
+            reading: s.to_string(),
+            kind: SpanKind::Text,
+            normalized: None,
+            rule: None,
+        }),
+    }
+}
+
+// items 중 list에 없는 것만 순서대로 붙인다.
+pub(crate) fn extend_unique<T: PartialEq>(list: &mut Vec<T>, items: Vec<T>) {
+    for item in items {
+        if !list.contains(&item) {
+            list.push(item);
+        }
+    }
+}
@@ -0,0 +1,186 @@
+// 사전 원본의 한 줄을 읽는다. build.rs에서도 #[path]로 이 파일을 가져가 사용하므로 crate 안의 다른 모듈을 참조하지 않는다.
+//
+// 두 가지 형식을 모두 받는다.
+//   1. 기존 형식: "한자,한글" (콤마로 나눈 두 필드)
+//        伽,가
+//        女子,여자
+//   2. 구조화 형식: 탭으로 나눈 필드. 뒤쪽의 빈 필드는 생략할 수 있다.
+//        한자  읽기  훈  부수  총획  급수  교육용
+//        樂    악,락,요  노래/즐길/좋아할  木+11  15  6급II  중학교
+//      - 읽기: 콤마로 나눈 한글 음절. 자주 쓰는 순서로 적고, "락:2"처럼 순위를 직접 적을 수도 있다.
+//      - 부수: "부수+나머지획수" (예: 水+5). 나머지 획수를 모르면 부수만 적는다.
+//      - 급수: 한자능력검정시험 급수 (8급, 7급II, ..., 1급, 특급II, 특급)
+//      - 교육용: 교육용 기초한자 1800자 중 "중학교" 또는 "고등학교"
+// 빈 줄과 '#'으로 시작하는 줄은 무시한다.
+//
+// 사전에 없는 호환용 한자와 부수 글자의 읽기(derived_readings)도 여기서 만들어 빌드할 때와 실행 중에 같은 규칙을 쓴다.
+
+use serde::Serialize;
+use unicode_normalization::char::{decompose_canonical, decompose_compatible};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Reading {
+    pub hangul: char,
+    pub rank: u8,  // 1이 가장 자주 쓰이는 읽기
+}
+
+// 교육용 기초한자 구분
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EduLevel {
+    None,
+    Middle,  // 중학교용 900자
+    High,    // 고등학교용 900자
+}
+
+// 구조화 형식의 한 줄
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub hanja: char,
+    pub readings: Vec<Reading>,  // 순위 순서로 정렬되어 있다.
+    pub meaning: String,
+    pub radical: Option<char>,
+    pub extra_strokes: Option<u8>,  // 부수를 뺀 나머지 획수
+    pub strokes: Option<u8>,        // 총획
+    pub grade: Option<String>,
+    pub edu: EduLevel,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Line {
+    Blank,                 // 빈 줄, 주석
+    Pair(String, String),  // 기존 형식
+    Record(Record),        // 구조화 형식
+}
+
+pub fn parse_line(line: &str) -> Result<Line, String> {
+    let trimmed = line.trim();
+    if trimmed.is_empty() || trimmed.starts_with('#') {
+        return Ok(Line::Blank);
+    }
+    if line.contains('\t') {
+        return parse_record(line).map(Line::Record);
+    }
+
+    let parts: Vec<&str> = line.split(',').collect();
+    if parts.len() != 2 {
+        return Err(format!("콤마가 {}개입니다(1개여야 함)", parts.len() - 1));
+    }
+    let (key, value) = (parts[0].trim(), parts[1].trim());
+    if key.is_empty() {
+        return Err("key가 비어 있습니다".to_string());
+    }
+    if value.is_empty() {
+        return Err("value가 비어 있습니다".to_string());
+    }
+    Ok(Line::Pair(key.to_string(), value.to_string()))
+}
+
+fn parse_record(line: &str) -> Result<Record, String> {
+    let fields: Vec<&str> = line.split('\t').map(|f| f.trim()).collect();
+    if fields.len() > 7 {
+        return Err(format!("필드가 {}개입니다(최대 7개)", fields.len()));
+    }
+    let field = |i: usize| fields.get(i).copied().unwrap_or("");
+
+    let hanja = single_char(field(0)).ok_or(format!("한자 {:?}는 한 글자가 아닙니다", field(0)))?;
+
+    let mut readings = Vec::new();
+    for (i, item) in field(1).split(',').map(|r| r.trim()).filter(|r| !r.is_empty()).enumerate() {
+        let (hangul, rank) = match item.split_once(':') {
+            Some((hangul, rank)) => {
+                let rank = rank.trim().parse::<u8>().map_err(|_| format!("읽기 순위 {:?}가 숫자가 아닙니다", rank))?;
+                (hangul.trim(), rank)
+            },
+            None => (item, (i + 1) as u8),
+        };
+        let hangul = single_char(hangul).ok_or(format!("읽기 {:?}는 한 글자가 아닙니다", hangul))?;
+        readings.push(Reading { hangul, rank });
+    }
+    if readings.is_empty() {
+        return Err("읽기가 비어 있습니다".to_string());
+    }
+    readings.sort_by_key(|r| r.rank);
+
+    let (radical, extra_strokes) = match field(3) {
+        "" => (None, None),
+        radical => {
+            let (radical, extra) = match radical.split_once('+') {
+                Some((radical, extra)) => {
+                    let extra = extra.trim().parse::<u8>().map_err(|_| format!("부수 나머지 획수 {:?}가 숫자가 아닙니다", extra))?;
+                    (radical.trim(), Some(extra))
+                },
+                None => (radical, None),
+            };
+            (Some(single_char(radical).ok_or(format!("부수 {:?}는 한 글자가 아닙니다", radical))?), extra)
+        }
+    };
+
+    let strokes = match field(4) {
+        "" => None,
+        strokes => Some(strokes.parse::<u8>().map_err(|_| format!("총획 {:?}가 숫자가 아닙니다", strokes))?),
+    };
+
+    let grade = match field(5) {
+        "" => None,
+        grade => Some(grade.to_string()),
+    };
+
+    let edu = match field(6) {
+        "" => EduLevel::None,
+        "중학교" => EduLevel::Middle,
+        "고등학교" => EduLevel::High,
+        edu => return Err(format!("교육용 구분 {:?}는 \"중학교\" 또는 \"고등학교\"여야 합니다", edu)),
+    };
+
+    Ok(Record {
+        hanja,
+        readings,
+        meaning: field(2).to_string(),
+        radical,
+        extra_strokes,
+        strokes,
+        grade,
+        edu,
+    })
+}
+
+fn single_char(s: &str) -> Option<char> {
+    let mut chars = s.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    }
+}
+
+// 사전에 없는 호환용 한자와 부수 글자는 대응하는 통합 한자의 읽기를 사용한다.
+//   호환용 한자(U+F900~, U+2F800~): 정규 분해(丽 U+2F800 -> 丽 U+4E3D)
+//   강희부수, 부수 보충(U+2F00~, U+2E80~): 호환 분해(⼀ U+2F00 -> 一 U+4E00)
+// reading은 한 글자 사전에서 읽기를 찾는 함수이고, 사전에 더할 (글자, 읽기)를 돌려준다.
+pub fn derived_readings(reading: impl Fn(char) -> Option<char>) -> Vec<(char, char)> {
+    let ranges: [(u32, u32, bool); 4] = [
+        (0x2E80, 0x2EFF, false),
+        (0x2F00, 0x2FDF, false),
+        (0xF900, 0xFAFF, true),
+        (0x2F800, 0x2FA1F, true),
+    ];
+    let mut derived = Vec::new();
+    for (start, end, canonical) in ranges {
+        for c in (start..=end).filter_map(char::from_u32) {
+            let mut decomposed = Vec::new();
+            if canonical {
+                decompose_canonical(c, |d| decomposed.push(d));
+            } else {
+                decompose_compatible(c, |d| decomposed.push(d));
+            }
+            if let [unified] = decomposed[..] {
+                if unified != c && reading(c).is_none() {
+                    if let Some(hangul) = reading(unified) {
+                        derived.push((c, hangul));
+                    }
+                }
+            }
+        }
+    }
+    derived
+}
@@ -0,0 +1,211 @@
+// 문서 파일(EPUB, DOCX)의 본문을 변환한다.
+// 두 형식 모두 zip 파일이다. 본문 파일(EPUB의 XHTML, DOCX의 WordprocessingML)만 변환해서 다시 압축하고,
+// 나머지 파일(이미지, 스타일, mimetype 등)은 압축된 내용 그대로 같은 순서로 복사한다.
+// DOCX는 한 단어가 서식이 다른 여러 run(<w:r>)으로 나뉘어 있는 경우가 많으므로,
+// 문단 안에서 이어진 <w:t>의 텍스트를 합쳐서 단어를 찾고, 변환한 단어는 그 단어가 시작하는 <w:t>에 넣는다.
+
+use std::io::{Cursor, Read, Write};
+
+use serde::Serialize;
+use zip::write::SimpleFileOptions;
+use zip::{ZipArchive, ZipWriter};
+
+use crate::conversion;
+use crate::html::HtmlOptions;
+use crate::{ConvertOptions, Conversion, Dictionary, MappedChar, OutputMode};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DocumentKind {
+    Epub,
+    Docx,
+}
+
+impl DocumentKind {
+    // 파일 이름의 확장자로 판단한다.
+    pub fn from_file_name(name: &str) -> Option<DocumentKind> {
+        let extension = name.rsplit_once('.')?.1.to_ascii_lowercase();
+        match extension.as_str() {
+            "epub" => Some(DocumentKind::Epub),
+            "docx" => Some(DocumentKind::Docx),
+            _ => None,
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            DocumentKind::Epub => "application/epub+zip",
+            DocumentKind::Docx => "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+        }
+    }
+
+    // zip 안의 파일 중 변환할 본문 파일인지
+    fn is_content(&self, path: &str) -> bool {
+        let lower = path.to_ascii_lowercase();
+        match self {
+            DocumentKind::Epub => [".xhtml", ".html", ".htm"].iter().any(|ext| lower.ends_with(ext)),
+            DocumentKind::Docx => {
+                let Some(name) = lower.strip_prefix("word/") else { return false };
+                let Some(stem) = name.strip_suffix(".xml") else { return false };
+                let numbered = |prefix: &str| stem.strip_prefix(prefix).is_some_and(|n| n.chars().all(|c| c.is_ascii_digit()));
+                matches!(stem, "document" | "footnotes" | "endnotes" | "comments") || numbered("header") || numbered("footer")
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DocumentConversion {
+    pub bytes: Vec<u8>,   // 변환한 문서 파일
+    pub converted: bool,  // 사전에서 찾아 변환한 한자가 하나라도 있는지
+    pub parts: usize,     // 변환한 본문 파일 수
+    pub unknown: Vec<char>,
+    pub mapped: Vec<MappedChar>,
+}
+
+impl Dictionary {
+    // zip 파일이 아니거나 읽을 수 없으면 Err. DOCX는 ruby 형식을 지원하지 않는다.
+    pub fn convert_document(&self, bytes: &[u8], kind: DocumentKind, options: &ConvertOptions) -> Result<DocumentConversion, String> {
+        if kind == DocumentKind::Docx && options.mode == OutputMode::Ruby {
+            return Err("DOCX 문서는 ruby 형식을 지원하지 않습니다. 다른 출력 형식을 지정해 주세요.".to_string());
+        }
+
+        let mut archive = ZipArchive::new(Cursor::new(bytes)).map_err(|e| format!("문서 파일을 열 수 없습니다: {}", e))?;
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        let mut result = DocumentConversion { bytes: Vec::new(), converted: false, parts: 0, unknown: Vec::new(), mapped: Vec::new() };
+        let html = HtmlOptions::default();
+
+        for i in 0..archive.len() {
+            // 1. 본문 파일이 아니면 압축된 그대로 복사한다.
+            let name = archive.name_for_index(i).unwrap_or("").to_string();
+            if !kind.is_content(&name) {
+                let file = archive.by_index_raw(i).map_err(zip_error)?;
+                writer.raw_copy_file(file).map_err(zip_error)?;
+                continue;
+            }
+
+            // 2. 본문 파일은 압축을 풀어 변환하고, 같은 압축 방식과 수정 시각으로 다시 넣는다.
+            let mut file = archive.by_index(i).map_err(zip_error)?;
+            let mut text = String::new();
+            file.read_to_string(&mut text).map_err(|e| format!("{}: {}", name, e))?;
+            let file_options = SimpleFileOptions::default()
+                .compression_method(file.compression())
+                .last_modified_time(file.last_modified().unwrap_or_default());
+            drop(file);
+
+            let (converted, conversions) = match kind {
+                DocumentKind::Epub => {
+                    let conversion = self.convert_html(&text, options, &html);
+                    (conversion.render(options.mode), vec![conversion])
+                },
+                DocumentKind::Docx => self.convert_wordml(&text, options),
+            };
+            for conversion in conversions {
+                result.converted |= conversion.is_converted();
+                conversion::extend_unique(&mut result.unknown, conversion.unknown());
+                conversion::extend_unique(&mut result.mapped, conversion.mapped());
+            }
+            result.parts += 1;
+
+            writer.start_file(name.as_str(), file_options).map_err(zip_error)?;
+            writer.write_all(converted.as_bytes()).map_err(|e| format!("{}: {}", name, e))?;
+        }
+
+        result.bytes = writer.finish().map_err(zip_error)?.into_inner();
+        Ok(result)
+    }
+
+    // WordprocessingML의 문단마다 이어진 <w:t> 텍스트를 합쳐서 변환한다.
+    // 문단(<w:p>), 탭, 줄바꿈, 이미 읽기를 단 <w:ruby>에서 텍스트를 끊는다. 삭제한 글자(<w:delText>)와 필드 코드는 변환하지 않는다.
+    fn convert_wordml(&self, xml: &str, options: &ConvertOptions) -> (String, Vec<Conversion>) {
+        let mut replacements: Vec<(usize, usize, String)> = Vec::new();
+        let mut conversions = Vec::new();
+        let mut texts: Vec<(usize, usize)> = Vec::new();  // 지금 문단에서 이어진 <w:t>의 내용 위치
+        let mut ruby_depth = 0;
+        let mut flush = |texts: &mut Vec<(usize, usize)>| {
+            if !texts.is_empty() {
+                conversions.push(self.convert_runs(xml, texts, options, &mut replacements));
+                texts.clear();
+            }
+        };
+
+        let mut pos = 0;
+        while let Some(i) = xml[pos..].find('<') {
+            let start = pos + i;
+            let Some(len) = xml[start..].find('>') else { break };
+            let end = start + len + 1;
+            let tag = &xml[start + 1..end - 1];
+            let closing = tag.starts_with('/');
+            let name = tag.trim_start_matches('/').split(|c: char| c.is_whitespace() || c == '/').next().unwrap_or("");
+            pos = end;
+
+            match name {
+                "w:t" if !closing && !tag.ends_with('/') => {
+                    let close = xml[end..].find("</w:t>").map_or(xml.len(), |i| end + i);
+                    if ruby_depth == 0 {
+                        texts.push((end, close));
+                    }
+                    pos = close;
+                },
+                "w:ruby" => {
+                    flush(&mut texts);
+                    if closing {
+                        ruby_depth -= 1;
+                    } else if !tag.ends_with('/') {
+                        ruby_depth += 1;
+                    }
+                },
+                "w:p" | "w:tab" | "w:br" | "w:cr" | "w:txbxContent" => flush(&mut texts),
+                _ => {},
+            }
+        }
+        flush(&mut texts);
+
+        // 뒤에서부터 바꾸어 앞쪽 위치가 바뀌지 않게 한다.
+        let mut converted = xml.to_string();
+        for (start, end, text) in replacements.into_iter().rev() {
+            converted.replace_range(start..end, &text);
+        }
+        (converted, conversions)
+    }
+
+    // 여러 <w:t>의 내용을 합쳐서 변환하고, 바뀐 내용을 replacements에 넣는다.
+    // 변환하지 않은 글자는 원래 <w:t>에 남기고, 변환한 단어는 그 단어가 시작하는 <w:t>에 넣는다.
+    fn convert_runs(&self, xml: &str, texts: &[(usize, usize)], options: &ConvertOptions, replacements: &mut Vec<(usize, usize, String)>) -> Conversion {
+        let mut joined = String::new();
+        let mut bounds = Vec::new();  // joined에서 각 <w:t>의 (시작, 끝)
+        for (start, end) in texts {
+            bounds.push((joined.len(), joined.len() + end - start));
+            joined.push_str(&xml[*start..*end]);
+        }
+
+        let conversion = self.convert(&joined, options);
+        if !conversion.spans.iter().any(|span| span.is_hanja()) {
+            return conversion;
+        }
+
+        let mut outputs = vec![String::new(); texts.len()];
+        for piece in conversion.render_pieces(options.mode) {
+            if piece.text == joined[piece.start..piece.end] {
+                for (i, (start, end)) in bounds.iter().enumerate() {
+                    let (from, to) = (piece.start.max(*start), piece.end.min(*end));
+                    if from < to {
+                        outputs[i].push_str(&joined[from..to]);
+                    }
+                }
+            } else if let Some(i) = bounds.iter().position(|(start, end)| *start <= piece.start && piece.start < *end) {
+                outputs[i].push_str(&piece.text);
+            }
+        }
+        for ((start, end), output) in texts.iter().zip(outputs) {
+            if xml[*start..*end] != output {
+                replacements.push((*start, *end, output));
+            }
+        }
+        conversion
+    }
+}
+
+fn zip_error(e: zip::result::ZipError) -> String {
+    format!("문서 파일을 읽을 수 없습니다: {}", e)
+}
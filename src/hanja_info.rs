@@ -0,0 +1,66 @@
+// 한자의 훈, 부수, 획수, 급수 등을 담은 구조화 형식의 사전 (형식은 dic_format.rs 참조)
+// 필드: 한자, 읽기(자주 쓰는 순서), 훈(읽기 순서대로 '/'로 구분), 부수+나머지획수, 총획, 한자능력검정시험 급수, 교육용 기초한자
+// 변환에 쓰는 기본 읽기는 hanja_char.rs의 HANJA_BASIC을 따르고, 여기 있는 읽기는 한자 정보로 제공한다.
+// 지금은 8급 한자 50자와 읽기가 여럿인 한자 몇 자만 있다. 여기 없는 한자는 읽기만 있고 훈, 부수, 획수, 급수는 없다.
+pub const HANJA_INFO:&str = 
+r#"校	교	학교	木+6	10	8급	중학교
+敎	교	가르칠	攴+7	11	8급	중학교
+九	구	아홉	乙+1	2	8급	중학교
+國	국	나라	囗+8	11	8급	중학교
+軍	군	군사	車+2	9	8급	중학교
+金	금,김	쇠/성	金+0	8	8급	중학교
+南	남	남녘	十+7	9	8급	중학교
+女	녀	계집	女+0	3	8급	중학교
+年	년	해	干+3	6	8급	중학교
+大	대	큰	大+0	3	8급	중학교
+東	동	동녘	木+4	8	8급	중학교
+六	륙	여섯	八+2	4	8급	중학교
+萬	만	일만	艸+9	13	8급	중학교
+母	모	어미	毋+1	5	8급	중학교
+木	목	나무	木+0	4	8급	중학교
+門	문	문	門+0	8	8급	중학교
+民	민	백성	氏+1	5	8급	중학교
+白	백	흰	白+0	5	8급	중학교
+父	부	아비	父+0	4	8급	중학교
+北	북,배	북녘/달아날	匕+3	5	8급	중학교
+四	사	넉	囗+2	5	8급	중학교
+山	산	메	山+0	3	8급	중학교
+三	삼	석	一+2	3	8급	중학교
+生	생	날	生+0	5	8급	중학교
+西	서	서녘	襾+0	6	8급	중학교
+先	선	먼저	儿+4	6	8급	중학교
+小	소	작을	小+0	3	8급	중학교
+水	수	물	水+0	4	8급	중학교
+室	실	집	宀+6	9	8급	중학교
+十	십	열	十+0	2	8급	중학교
+五	오	다섯	二+2	4	8급	중학교
+王	왕	임금	玉+0	4	8급	중학교
+外	외	바깥	夕+2	5	8급	중학교
+月	월	달	月+0	4	8급	중학교
+二	이	두	二+0	2	8급	중학교
+人	인	사람	人+0	2	8급	중학교
+一	일	한	一+0	1	8급	중학교
+日	일	날	日+0	4	8급	중학교
+長	장	긴	長+0	8	8급	중학교
+弟	제	아우	弓+4	7	8급	중학교
+中	중	가운데	丨+3	4	8급	중학교
+靑	청	푸를	靑+0	8	8급	중학교
+寸	촌	마디	寸+0	3	8급	중학교
+七	칠	일곱	一+1	2	8급	중학교
+土	토	흙	土+0	3	8급	중학교
+八	팔	여덟	八+0	2	8급	중학교
+學	학	배울	子+13	16	8급	중학교
+韓	한	나라	韋+8	17	8급	중학교
+兄	형	형	儿+3	5	8급	중학교
+火	화	불	火+0	4	8급	중학교
+車	거,차	수레/수레	車+0	7	7급II	중학교
+樂	락,악,요	즐길/노래/좋아할	木+11	15	6급II	중학교
+度	도,탁	법도/헤아릴	广+6	9	6급	중학교
+行	행,항	다닐/항렬	行+0	6	6급	중학교
+見	견,현	볼/뵈올	見+0	7	5급II	중학교
+說	설,세,열	말씀/달랠/기쁠	言+7	14	5급II	중학교
+惡	악,오	악할/미워할	心+8	12	5급II	중학교
+復	복,부	회복할/다시	彳+9	12	4급II	중학교
+殺	살,쇄	죽일/감할	殳+7	11	4급II	중학교
+更	경,갱	고칠/다시	曰+3	7	4급	중학교
+易	역,이	바꿀/쉬울	日+4	8	4급	중학교"#;
@@ -0,0 +1,815 @@
+// 중국 간체자, 일본 신자체 -> 번체자(정자) 매핑 테이블
+// ("간체자/신자체,번체자") 형태. 중국어, 일본어 자료에서 옮긴 글(经济, 経済)을 변환할 때 사용한다.
+// 간체자 중에는 국내에서 다른 글자로 쓰는 한자(体 분, 机 궤 등)도 있으므로, 요청에서 켰을 때만 적용한다.
+pub const HANJA_SIMPLIFIED:&str = 
+r#"# 중국 간체자
+经,經
+济,濟
+国,國
+学,學
+会,會
+发,發
+来,來
+时,時
+个,個
+们,們
+说,說
+对,對
+这,這
+长,長
+为,爲
+现,現
+动,動
+产,產
+过,過
+样,樣
+开,開
+关,關
+东,東
+车,車
+还,還
+进,進
+体,體
+机,機
+实,實
+问,問
+头,頭
+门,門
+见,見
+听,聽
+觉,覺
+书,書
+记,記
+话,話
+语,語
+读,讀
+写,寫
+认,認
+识,識
+让,讓
+请,請
+谁,誰
+论,論
+设,設
+计,計
+议,議
+讲,講
+谈,談
+调,調
+质,質
+贵,貴
+买,買
+卖,賣
+费,費
+资,資
+贸,貿
+货,貨
+财,財
+责,責
+贫,貧
+赞,贊
+银,銀
+钱,錢
+铁,鐵
+钢,鋼
+错,錯
+镜,鏡
+马,馬
+鸟,鳥
+鱼,魚
+龙,龍
+龟,龜
+齿,齒
+边,邊
+远,遠
+运,運
+选,選
+递,遞
+达,達
+迁,遷
+连,連
+农,農
+务,務
+劳,勞
+势,勢
+华,華
+单,單
+卫,衛
+历,歷
+压,壓
+厅,廳
+县,縣
+参,參
+双,雙
+变,變
+号,號
+园,園
+围,圍
+图,圖
+圆,圓
+场,場
+坏,壞
+块,塊
+声,聲
+处,處
+备,備
+夺,奪
+奋,奮
+妇,婦
+娱,娛
+孙,孫
+宝,寶
+审,審
+宪,憲
+宫,宮
+宽,寬
+导,導
+寿,壽
+将,將
+尔,爾
+尘,塵
+层,層
+属,屬
+岁,歲
+岛,島
+币,幣
+师,師
+帐,帳
+带,帶
+帮,幫
+广,廣
+庆,慶
+库,庫
+应,應
+庙,廟
+废,廢
+异,異
+张,張
+弹,彈
+归,歸
+当,當
+录,錄
+彻,徹
+忆,憶
+忧,憂
+怀,懷
+态,態
+总,總
+恋,戀
+恶,惡
+惊,驚
+惯,慣
+战,戰
+户,戶
+执,執
+扩,擴
+扫,掃
+扬,揚
+护,護
+报,報
+担,擔
+拥,擁
+择,擇
+挥,揮
+损,損
+换,換
+摄,攝
+敌,敵
+数,數
+断,斷
+无,無
+旧,舊
+显,顯
+晓,曉
+暂,暫
+术,術
+杀,殺
+杂,雜
+权,權
+条,條
+极,極
+构,構
+枪,槍
+标,標
+树,樹
+桥,橋
+检,檢
+楼,樓
+欢,歡
+欧,歐
+残,殘
+毕,畢
+气,氣
+汉,漢
+汤,湯
+没,沒
+沟,溝
+泪,淚
+泽,澤
+洁,潔
+浅,淺
+测,測
+浓,濃
+涛,濤
+润,潤
+涨,漲
+渐,漸
+温,溫
+湾,灣
+湿,濕
+满,滿
+滚,滾
+灭,滅
+灯,燈
+灵,靈
+炉,爐
+点,點
+炼,煉
+热,熱
+爱,愛
+爷,爺
+牵,牽
+犹,猶
+独,獨
+狮,獅
+猎,獵
+献,獻
+环,環
+电,電
+画,畫
+畅,暢
+疗,療
+盐,鹽
+监,監
+盖,蓋
+盘,盤
+矿,礦
+码,碼
+础,礎
+确,確
+礼,禮
+祸,禍
+离,離
+积,積
+称,稱
+穷,窮
+窃,竊
+竞,競
+笔,筆
+简,簡
+类,類
+粮,糧
+紧,緊
+纠,糾
+红,紅
+约,約
+级,級
+纪,紀
+纯,純
+纲,綱
+纳,納
+纸,紙
+线,線
+练,練
+组,組
+细,細
+织,織
+终,終
+绍,紹
+结,結
+绕,繞
+给,給
+络,絡
+绝,絕
+统,統
+继,繼
+绩,績
+续,續
+维,維
+综,綜
+绿,綠
+缓,緩
+编,編
+缘,緣
+网,網
+罗,羅
+罚,罰
+职,職
+联,聯
+肃,肅
+胶,膠
+脑,腦
+脚,腳
+脸,臉
+舰,艦
+艺,藝
+节,節
+苏,蘇
+茧,繭
+荣,榮
+药,藥
+获,獲
+营,營
+虏,虜
+虑,慮
+虽,雖
+蚀,蝕
+补,補
+装,裝
+规,規
+视,視
+览,覽
+观,觀
+誉,譽
+订,訂
+讨,討
+训,訓
+讯,訊
+许,許
+证,證
+评,評
+诉,訴
+诊,診
+词,詞
+译,譯
+试,試
+诗,詩
+诚,誠
+该,該
+详,詳
+误,誤
+课,課
+谋,謀
+谢,謝
+谦,謙
+谨,謹
+贝,貝
+负,負
+贡,貢
+败,敗
+购,購
+贯,貫
+贱,賤
+贴,貼
+贷,貸
+贺,賀
+赏,賞
+赖,賴
+赛,賽
+赵,趙
+趋,趨
+跃,躍
+践,踐
+轨,軌
+转,轉
+轮,輪
+软,軟
+轻,輕
+载,載
+较,較
+辅,輔
+辆,輛
+辈,輩
+输,輸
+辞,辭
+辽,遼
+迈,邁
+违,違
+逻,邏
+遗,遺
+邮,郵
+邻,鄰
+郑,鄭
+酱,醬
+释,釋
+针,針
+钟,鐘
+钓,釣
+铃,鈴
+铅,鉛
+锁,鎖
+锋,鋒
+锐,銳
+键,鍵
+镇,鎭
+闭,閉
+闲,閑
+间,間
+闷,悶
+闹,鬧
+闻,聞
+阀,閥
+阁,閣
+阅,閱
+队,隊
+阳,陽
+阴,陰
+阵,陣
+阶,階
+际,際
+陆,陸
+陈,陳
+险,險
+随,隨
+隐,隱
+难,難
+雾,霧
+静,靜
+须,須
+顶,頂
+项,項
+顺,順
+预,預
+领,領
+频,頻
+题,題
+颜,顏
+额,額
+风,風
+飞,飛
+饭,飯
+饮,飮
+馆,館
+饱,飽
+饰,飾
+驱,驅
+驾,駕
+验,驗
+骑,騎
+鲜,鮮
+鸡,鷄
+鸣,鳴
+麦,麥
+齐,齊
+龄,齡
+亚,亞
+仅,僅
+从,從
+仓,倉
+众,衆
+优,優
+伤,傷
+伦,倫
+伟,偉
+传,傳
+侦,偵
+侧,側
+债,債
+倾,傾
+储,儲
+儿,兒
+党,黨
+兰,蘭
+兴,興
+养,養
+兽,獸
+军,軍
+冻,凍
+凤,鳳
+凭,憑
+击,擊
+刘,劉
+则,則
+刚,剛
+创,創
+删,刪
+别,別
+剧,劇
+剑,劍
+劝,勸
+办,辦
+励,勵
+劲,勁
+协,協
+卢,盧
+卧,臥
+厉,厲
+叙,敍
+吓,嚇
+吕,呂
+吴,吳
+员,員
+呜,嗚
+响,響
+唤,喚
+喷,噴
+嘱,囑
+团,團
+圣,聖
+坚,堅
+坛,壇
+坟,墳
+垄,壟
+垒,壘
+垦,墾
+墙,牆
+壮,壯
+壳,殼
+够,夠
+夹,夾
+奖,獎
+妆,妝
+娄,婁
+婴,嬰
+宁,寧
+宠,寵
+宾,賓
+寝,寢
+寻,尋
+尝,嘗
+尧,堯
+届,屆
+岗,崗
+岭,嶺
+峡,峽
+帅,帥
+帜,幟
+庐,廬
+庞,龐
+弃,棄
+弥,彌
+弯,彎
+径,徑
+恳,懇
+恼,惱
+悬,懸
+惩,懲
+惭,慚
+惨,慘
+愤,憤
+懒,懶
+戏,戲
+扑,撲
+扰,擾
+抚,撫
+抢,搶
+拟,擬
+拨,撥
+拣,揀
+挡,擋
+挤,擠
+捞,撈
+捡,撿
+掷,擲
+揽,攬
+摆,擺
+摇,搖
+撑,撐
+敛,斂
+斋,齋
+斩,斬
+旷,曠
+昼,晝
+晒,曬
+晋,晉
+晕,暈
+杨,楊
+枣,棗
+栋,棟
+栏,欄
+梦,夢
+歼,殲
+毁,毀
+汇,匯
+沧,滄
+泼,潑
+浆,漿
+浑,渾
+涩,澀
+渊,淵
+渔,漁
+溃,潰
+滞,滯
+滤,濾
+滥,濫
+滨,濱
+潜,潛
+澜,瀾
+灾,災
+灿,燦
+烂,爛
+烛,燭
+烦,煩
+烧,燒
+焕,煥
+牍,牘
+狭,狹
+猪,豬
+猫,貓
+玛,瑪
+琐,瑣
+痴,癡
+瘾,癮
+盏,盞
+睁,睜
+矫,矯
+砖,磚
+硕,碩
+祷,禱
+秃,禿
+稳,穩
+窍,竅
+窑,窯
+窜,竄
+竖,豎
+笼,籠
+筹,籌
+签,簽
+篮,籃
+粪,糞
+# 일본 신자체 (간체자와 같은 글자는 위에 있다)
+経,經
+済,濟
+対,對
+図,圖
+発,發
+実,實
+伝,傳
+区,區
+売,賣
+読,讀
+広,廣
+両,兩
+鉄,鐵
+楽,樂
+変,變
+辺,邊
+与,與
+為,爲
+即,卽
+悪,惡
+挙,擧
+観,觀
+帰,歸
+関,關
+軽,輕
+郷,鄕
+並,竝
+仏,佛
+塩,鹽
+蚕,蠶
+団,團
+戦,戰
+銭,錢
+訳,譯
+駅,驛
+沢,澤
+択,擇
+圧,壓
+仮,假
+価,價
+触,觸
+続,續
+処,處
+乱,亂
+児,兒
+歯,齒
+尽,盡
+気,氣
+験,驗
+険,險
+検,檢
+剣,劍
+権,權
+歓,歡
+勧,勸
+薬,藥
+栄,榮
+営,營
+労,勞
+蛍,螢
+覚,覺
+黒,黑
+徳,德
+聴,聽
+庁,廳
+恵,惠
+穂,穗
+様,樣
+桜,櫻
+渋,澁
+獣,獸
+縦,縱
+従,從
+茎,莖
+猟,獵
+髪,髮
+抜,拔
+弾,彈
+単,單
+禅,禪
+巣,巢
+悩,惱
+脳,腦
+拝,拜
+穏,穩
+隠,隱
+殻,殼
+拡,擴
+鉱,鑛
+亜,亞
+囲,圍
+壱,壹
+奥,奧
+横,橫
+殴,毆
+絵,繪
+懐,懷
+壊,壞
+渇,渴
+巻,卷
+陥,陷
+寛,寬
+偽,僞
+戯,戲
+犠,犧
+拠,據
+挟,挾
+暁,曉
+駆,驅
+勲,勳
+掲,揭
+渓,溪
+継,繼
+鶏,鷄
+撃,擊
+県,縣
+倹,儉
+圏,圈
+顕,顯
+厳,嚴
+砕,碎
+斎,齋
+剤,劑
+雑,雜
+桟,棧
+賛,贊
+釈,釋
+収,收
+粛,肅
+奨,獎
+焼,燒
+渉,涉
+乗,乘
+浄,淨
+剰,剩
+畳,疊
+縄,繩
+壌,壤
+嬢,孃
+譲,讓
+醸,釀
+粋,粹
+酔,醉
+髄,髓
+枢,樞
+瀬,瀨
+斉,齊
+摂,攝
+専,專
+繊,纖
+争,爭
+荘,莊
+捜,搜
+挿,插
+総,總
+騒,騷
+増,增
+蔵,藏
+臓,臟
+堕,墮
+帯,帶
+滝,瀧
+胆,膽
+遅,遲
+鋳,鑄
+逓,遞
+転,轉
+盗,盜
+稲,稻
+闘,鬪
+弐,貳
+廃,廢
+蛮,蠻
+払,拂
+舗,鋪
+歩,步
+翻,飜
+毎,每
+満,滿
+黙,默
+揺,搖
+謡,謠
+頼,賴
+覧,覽
+竜,龍
+涙,淚
+塁,壘
+隷,隸
+霊,靈
+齢,齡
+暦,曆
+歴,歷
+郎,郞
+禄,祿
+"#;
@@ -0,0 +1,60 @@
+// 이체자(異體字) 매핑 테이블: 모양은 다르지만 같은 글자로 쓰는 한자
+// ("이체자,표준자") 형태. 표준자는 국내에서 주로 쓰는 자형(교육용 기초한자 기준)이다.
+// 호환용 한자(U+F900~)는 유니코드 정규화로 찾으므로 여기에 넣지 않는다.
+pub const HANJA_VARIANT:&str = 
+r#"峰,峯
+嶋,島
+羣,群
+畧,略
+雞,鷄
+烟,煙
+牀,床
+卻,却
+劒,劍
+綫,線
+眾,衆
+裡,裏
+窗,窓
+兔,兎
+舘,館
+教,敎
+説,說
+内,內
+青,靑
+清,淸
+呉,吳
+岳,嶽
+碍,礙
+効,效
+姉,姊
+册,冊
+飲,飮
+録,錄
+緑,綠
+黄,黃
+尚,尙
+兑,兌
+税,稅
+鋭,銳
+脱,脫
+悦,悅
+閲,閱
+遥,遙
+即,卽
+既,旣
+概,槪
+鄉,鄕
+真,眞
+慎,愼
+顛,顚
+填,塡
+鎮,鎭
+強,强
+栁,柳
+呌,叫
+麪,麵
+盃,杯
+囘,回
+邨,村
+秌,秋
+"#;
@@ -119,19 +119,10 @@ r#"客車,객차
 喝火,할화
 賈船,고선
 商賈,상고
-初八日,초파일
-六月,유월
-十月,시월
-木瓜,모과
-困難,곤란
 乖愎,괴팍
 娑婆,사바
 邯鄲之夢,한단지몽
 唵川面,옴천면
 紙杻驛,지축역
-庫間,곳간
-貰房,셋방
-數字,숫자
-車間,찻간
-退間,툇간
-回數,횟수"#;
\ No newline at end of file
+五六月,오뉴월
+十六月,십육월"#;
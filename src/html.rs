@@ -0,0 +1,280 @@
+// HTML 문서에서 텍스트 노드의 한자만 변환한다.
+// 태그, 속성 값, 주석, <script>/<style>의 내용은 그대로 두고, 다음 요소 안의 텍스트도 변환하지 않는다.
+//   - 설정한 요소 (기본: DEFAULT_SKIP_ELEMENTS)
+//   - lang 속성이 한국어(ko)가 아닌 요소 (lang="zh", lang="ja" 등). 안쪽 요소에서 lang="ko"로 다시 변환할 수 있다.
+//   - translate="no" 또는 data-no-hanja 속성이 있는 요소
+// 마크업은 입력 그대로 출력하고 텍스트 노드만 바꾸므로, 입력이 올바른 HTML이면 출력도 올바른 HTML이다.
+
+use serde::{Deserialize, Serialize};
+
+use crate::conversion;
+use crate::{Conversion, ConvertOptions, Dictionary, OutputMode, Span};
+
+// 기본으로 변환하지 않는 요소. 이미 읽기를 단 <ruby>도 건너뛴다.
+pub const DEFAULT_SKIP_ELEMENTS: &[&str] = &["script", "style", "code", "pre", "kbd", "samp", "var", "textarea", "ruby"];
+
+// 내용을 태그로 해석하지 않는 요소. 내용은 변환하지 않는다.
+const RAW_TEXT_ELEMENTS: &[&str] = &["script", "style", "xmp", "iframe", "noembed", "noframes"];
+// 내용을 태그로 해석하지 않지만 글자로 보여 주는 요소. ruby 형식이 아니면 내용을 변환한다.
+const RCDATA_ELEMENTS: &[&str] = &["title", "textarea"];
+// 안쪽에 <ruby>를 넣을 수 없는 요소. ruby 형식이면 안쪽 텍스트를 변환하지 않는다.
+const NO_RUBY_ELEMENTS: &[&str] = &["ruby", "svg", "math"];
+// 닫는 태그가 없는 요소
+const VOID_ELEMENTS: &[&str] = &["area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source", "track", "wbr"];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HtmlOptions {
+    pub skip_elements: Vec<String>,  // 안쪽 텍스트를 변환하지 않을 요소 이름
+}
+
+impl Default for HtmlOptions {
+    fn default() -> HtmlOptions {
+        HtmlOptions {
+            skip_elements: DEFAULT_SKIP_ELEMENTS.iter().map(|name| name.to_string()).collect(),
+        }
+    }
+}
+
+impl HtmlOptions {
+    // 캐시 key, ETag 계산에 사용하는 옵션의 문자열 표현
+    pub fn key(&self) -> String {
+        format!("{:?}", self)
+    }
+
+    fn skips(&self, name: &str) -> bool {
+        self.skip_elements.iter().any(|skip| skip.trim().eq_ignore_ascii_case(name))
+    }
+}
+
+// 열려 있는 요소와 그 안쪽 텍스트를 변환할지 결정하는 상태
+#[derive(Debug, Clone, Copy, Default)]
+struct Scope {
+    skip: bool,          // 건너뛰는 요소이거나 data-no-hanja 안쪽. 안쪽에서 다시 켤 수 없다.
+    foreign: bool,       // lang 속성이 한국어가 아님
+    no_translate: bool,  // translate="no"
+    no_ruby: bool,       // <ruby>, <svg>, <math> 안쪽
+}
+
+impl Scope {
+    fn converts(&self, mode: OutputMode) -> bool {
+        let no_ruby = self.no_ruby && mode == OutputMode::Ruby;
+        !(self.skip || self.foreign || self.no_translate || no_ruby)
+    }
+}
+
+struct OpenElement {
+    name: String,
+    scope: Scope,
+}
+
+// 태그 하나
+struct Tag<'a> {
+    name: String,  // 소문자
+    closing: bool,
+    self_closing: bool,
+    attrs: Vec<(String, &'a str)>,  // (소문자 이름, 값)
+}
+
+impl<'a> Tag<'a> {
+    fn attr(&self, name: &str) -> Option<&'a str> {
+        self.attrs.iter().find(|(n, _)| n == name).map(|(_, value)| *value)
+    }
+}
+
+impl Dictionary {
+    // 결과의 Text 구간에는 텍스트 노드뿐 아니라 태그 등 변환하지 않은 부분이 모두 들어 있다.
+    pub fn convert_html(&self, input: &str, options: &ConvertOptions, html: &HtmlOptions) -> Conversion {
+        let mut spans: Vec<Span> = Vec::new();
+        let mut stack: Vec<OpenElement> = Vec::new();
+        let bytes = input.as_bytes();
+        let mut pos = 0;
+        let mut text_start = 0;
+
+        while pos < bytes.len() {
+            if bytes[pos] != b'<' {
+                pos += 1;
+                continue;
+            }
+            let Some((end, tag)) = scan_markup(input, pos) else {
+                pos += 1;  // 태그가 아닌 '<'는 텍스트
+                continue;
+            };
+
+            // 1. 앞의 텍스트 노드를 변환하고, 마크업은 그대로 둔다.
+            let scope = stack.last().map_or(Scope::default(), |open| open.scope);
+            self.convert_range(&mut spans, input, text_start, pos, scope.converts(options.mode), options);
+            conversion::push_plain(&mut spans, input, pos, end);
+            pos = end;
+            text_start = end;
+
+            // 2. 열고 닫는 태그에 따라 변환 범위를 바꾼다.
+            let Some(tag) = tag else { continue };
+            if tag.closing {
+                if let Some(i) = stack.iter().rposition(|open| open.name == tag.name) {
+                    stack.truncate(i);
+                }
+                continue;
+            }
+            if tag.self_closing || VOID_ELEMENTS.contains(&tag.name.as_str()) {
+                continue;
+            }
+            let scope = element_scope(scope, &tag, html);
+
+            // 3. <script>, <title> 등은 닫는 태그까지 텍스트 하나로 처리한다.
+            let raw = RAW_TEXT_ELEMENTS.contains(&tag.name.as_str());
+            if raw || RCDATA_ELEMENTS.contains(&tag.name.as_str()) {
+                let close = find_closing_tag(input, pos, &tag.name);
+                let converts = !raw && scope.converts(options.mode) && options.mode != OutputMode::Ruby;  // <title> 안에는 <ruby>를 넣을 수 없다.
+                self.convert_range(&mut spans, input, pos, close, converts, options);
+                pos = close;
+                text_start = close;
+            }
+            stack.push(OpenElement { name: tag.name, scope });
+        }
+
+        let scope = stack.last().map_or(Scope::default(), |open| open.scope);
+        self.convert_range(&mut spans, input, text_start, input.len(), scope.converts(options.mode), options);
+        Conversion { spans, rewrite: options.normalize == crate::Normalize::Rewrite }
+    }
+}
+
+// Markdown 본문 안의 인라인 HTML 태그 하나를 읽는다. (소문자 이름, 닫는 태그인지, 안쪽을 변환하지 않는지)
+// 닫는 태그가 없는 요소와 태그가 아닌 마크업(주석 등)은 None
+pub(crate) fn inline_tag(markup: &str, mode: OutputMode) -> Option<(String, bool, bool)> {
+    let (_, tag) = scan_markup(markup, 0)?;
+    let tag = tag?;
+    if tag.self_closing || VOID_ELEMENTS.contains(&tag.name.as_str()) {
+        return None;
+    }
+    let skips = !tag.closing && !element_scope(Scope::default(), &tag, &HtmlOptions::default()).converts(mode);
+    Some((tag.name, tag.closing, skips))
+}
+
+// 요소의 이름과 속성으로 안쪽 텍스트의 변환 범위를 정한다.
+fn element_scope(parent: Scope, tag: &Tag, html: &HtmlOptions) -> Scope {
+    let mut scope = parent;
+    if html.skips(&tag.name) || tag.attr("data-no-hanja").is_some() {
+        scope.skip = true;
+    }
+    if NO_RUBY_ELEMENTS.contains(&tag.name.as_str()) {
+        scope.no_ruby = true;
+    }
+    // lang="ko", "ko-KR", "ko-Kore"는 한국어. 빈 값은 언어를 모르는 것이므로 부모를 따른다.
+    if let Some(lang) = tag.attr("lang").or_else(|| tag.attr("xml:lang")) {
+        let primary = lang.trim().split(['-', '_']).next().unwrap_or("").to_ascii_lowercase();
+        if !primary.is_empty() {
+            scope.foreign = primary != "ko";
+        }
+    }
+    if let Some(translate) = tag.attr("translate") {
+        scope.no_translate = translate.trim().eq_ignore_ascii_case("no");
+    }
+    scope
+}
+
+// pos의 '<'부터 시작하는 마크업의 끝 위치와 태그. 주석, CDATA, <!DOCTYPE> 등은 태그가 None
+// 태그가 아니면(예: "a < b") None
+fn scan_markup(input: &str, pos: usize) -> Option<(usize, Option<Tag<'_>>)> {
+    let bytes = input.as_bytes();
+    let after = |from: usize, pattern: &str| input[from..].find(pattern).map_or(input.len(), |i| from + i + pattern.len());
+
+    if input[pos..].starts_with("<!--") {
+        return Some((after(pos + 4, "-->"), None));
+    }
+    if input[pos..].starts_with("<![CDATA[") {  // XHTML
+        return Some((after(pos + 9, "]]>"), None));
+    }
+    match bytes.get(pos + 1) {
+        Some(b'!') | Some(b'?') => Some((after(pos + 2, ">"), None)),
+        Some(b'/') if bytes.get(pos + 2).is_some_and(|b| b.is_ascii_alphabetic()) => {
+            let (name, _) = scan_name(bytes, pos + 2);
+            Some((after(pos + 2, ">"), Some(Tag { name, closing: true, self_closing: false, attrs: Vec::new() })))
+        },
+        Some(b) if b.is_ascii_alphabetic() => Some(scan_start_tag(input, pos)),
+        _ => None,
+    }
+}
+
+// <태그 이름="값" ...>
+// 태그가 끝나기 전에 입력이 끝나면 나머지 전체를 마크업으로 본다.
+fn scan_start_tag(input: &str, pos: usize) -> (usize, Option<Tag<'_>>) {
+    let bytes = input.as_bytes();
+    let (name, mut i) = scan_name(bytes, pos + 1);
+    let mut tag = Tag { name, closing: false, self_closing: false, attrs: Vec::new() };
+
+    loop {
+        while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+        match bytes.get(i) {
+            None => return (input.len(), None),
+            Some(b'>') => return (i + 1, Some(tag)),
+            Some(b'/') => {
+                tag.self_closing = bytes.get(i + 1) == Some(&b'>');
+                i += 1;
+                continue;
+            },
+            _ => {},
+        }
+
+        // 속성 이름
+        let name_start = i;
+        i += 1;  // 첫 글자는 '='여도 이름에 포함한다.
+        while i < bytes.len() && !matches!(bytes[i], b'=' | b'>' | b'/') && !bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+        let name = input[name_start..i].to_ascii_lowercase();
+        while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+        if bytes.get(i) != Some(&b'=') {
+            tag.attrs.push((name, ""));
+            continue;
+        }
+
+        // 속성 값: "값", '값', 따옴표 없는 값
+        i += 1;
+        while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+        let value = match bytes.get(i) {
+            Some(quote @ (b'"' | b'\'')) => {
+                let Some(len) = input[i + 1..].find(*quote as char) else { return (input.len(), None) };
+                let value = &input[i + 1..i + 1 + len];
+                i += len + 2;
+                value
+            },
+            _ => {
+                let value_start = i;
+                while i < bytes.len() && bytes[i] != b'>' && !bytes[i].is_ascii_whitespace() {
+                    i += 1;
+                }
+                &input[value_start..i]
+            },
+        };
+        tag.attrs.push((name, value));
+    }
+}
+
+// 태그 이름(소문자)과 이름 다음 위치
+fn scan_name(bytes: &[u8], start: usize) -> (String, usize) {
+    let mut i = start;
+    while i < bytes.len() && !matches!(bytes[i], b'/' | b'>') && !bytes[i].is_ascii_whitespace() {
+        i += 1;
+    }
+    (String::from_utf8_lossy(&bytes[start..i]).to_ascii_lowercase(), i)
+}
+
+// from 이후의 </name 위치. 없으면 입력의 끝
+fn find_closing_tag(input: &str, from: usize, name: &str) -> usize {
+    let bytes = input.as_bytes();
+    input[from..].match_indices("</")
+        .map(|(i, _)| from + i)
+        .find(|i| {
+            let name_end = i + 2 + name.len();
+            input.get(i + 2..name_end).is_some_and(|n| n.eq_ignore_ascii_case(name))
+                && bytes.get(name_end).is_none_or(|b| matches!(b, b'>' | b'/') || b.is_ascii_whitespace())
+        })
+        .unwrap_or(input.len())
+}
@@ -0,0 +1,125 @@
+// JSON 문서에서 선택한 문자열 값의 한자만 변환한다.
+// 선택자는 JSONPath("$.items[*].name")나 JSON Pointer("/items/0/name")로 지정한다.
+// 선택한 값이 객체나 배열이면 그 안의 문자열을 모두 변환한다. 선택자가 없으면 문서의 모든 문자열을 변환한다.
+// 객체의 key와 선택하지 않은 값은 그대로 두고, 같은 문자열을 여러 선택자가 선택해도 한 번만 변환한다.
+
+use std::collections::HashSet;
+
+use serde::Serialize;
+use serde_json::Value;
+use serde_json_path::JsonPath;
+
+use crate::conversion;
+use crate::{ConvertOptions, Dictionary, MappedChar};
+
+// 선택자 하나의 변환 결과
+#[derive(Debug, Clone, Serialize)]
+pub struct PathStats {
+    pub path: String,
+    pub matched: usize,    // 선택한 값의 수
+    pub strings: usize,    // 이 선택자로 변환한 문자열의 수 (앞의 선택자가 이미 변환한 문자열은 세지 않는다.)
+    pub converted: usize,  // 그중 사전에서 찾아 변환한 한자가 있는 문자열의 수
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub unknown: Vec<char>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonConversion {
+    pub document: Value,
+    pub converted: bool,  // 사전에서 찾아 변환한 한자가 하나라도 있는지
+    pub paths: Vec<PathStats>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub unknown: Vec<char>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub mapped: Vec<MappedChar>,
+}
+
+// 선택자. '$'로 시작하면 JSONPath, '/'로 시작하거나 빈 문자열이면 JSON Pointer
+enum Selector {
+    Path(JsonPath),
+    Pointer(String),
+}
+
+impl Selector {
+    fn parse(s: &str) -> Result<Selector, String> {
+        if s.starts_with('$') {
+            JsonPath::parse(s).map(Selector::Path).map_err(|e| format!("잘못된 JSONPath: {}: {}", s, e))
+        } else if s.is_empty() || s.starts_with('/') {
+            Ok(Selector::Pointer(s.to_string()))
+        } else {
+            Err(format!("선택자는 JSONPath('$'로 시작) 또는 JSON Pointer('/'로 시작)여야 합니다: {}", s))
+        }
+    }
+
+    // 선택한 값의 JSON Pointer 목록
+    fn locate(&self, document: &Value) -> Vec<String> {
+        match self {
+            Selector::Path(path) => path.query_located(document).locations().map(|location| location.to_json_pointer()).collect(),
+            Selector::Pointer(pointer) => document.pointer(pointer).map(|_| vec![pointer.clone()]).unwrap_or_default(),
+        }
+    }
+}
+
+impl Dictionary {
+    // 선택자가 잘못되었으면 Err
+    pub fn convert_json(&self, mut document: Value, paths: &[String], options: &ConvertOptions) -> Result<JsonConversion, String> {
+        // 1. 선택자를 모두 먼저 읽어서, 잘못된 선택자가 있으면 아무것도 변환하지 않는다.
+        let selectors = if paths.is_empty() {
+            vec![("$".to_string(), Selector::Pointer(String::new()))]
+        } else {
+            paths.iter().map(|path| Ok((path.clone(), Selector::parse(path)?))).collect::<Result<Vec<_>, String>>()?
+        };
+
+        let mut result = JsonConversion { document: Value::Null, converted: false, paths: Vec::new(), unknown: Vec::new(), mapped: Vec::new() };
+        let mut done: HashSet<String> = HashSet::new();  // 이미 변환한 문자열의 JSON Pointer
+        for (path, selector) in selectors {
+            // 2. 선택한 위치를 모두 찾은 다음 변환한다. 변환하는 동안 문서를 빌려 쓸 수 없기 때문이다.
+            let pointers = selector.locate(&document);
+            let mut stats = PathStats { path, matched: pointers.len(), strings: 0, converted: 0, unknown: Vec::new() };
+            for pointer in pointers {
+                let Some(value) = document.pointer_mut(&pointer) else { continue };
+                let mut visit = |value: &mut Value| {
+                    let Value::String(s) = value else { return };
+                    let conversion = self.convert(s, options);
+                    stats.strings += 1;
+                    if conversion.is_converted() {
+                        stats.converted += 1;
+                        *s = conversion.render(options.mode);
+                    }
+                    conversion::extend_unique(&mut stats.unknown, conversion.unknown());
+                    conversion::extend_unique(&mut result.mapped, conversion.mapped());
+                };
+                visit_strings(value, &mut pointer.clone(), &mut done, &mut visit);
+            }
+            result.converted |= stats.converted > 0;
+            conversion::extend_unique(&mut result.unknown, stats.unknown.clone());
+            result.paths.push(stats);
+        }
+        result.document = document;
+        Ok(result)
+    }
+}
+
+// value 안의 문자열마다 visit을 부른다. pointer는 value의 JSON Pointer이고, done에 있는 문자열은 건너뛴다.
+fn visit_strings(value: &mut Value, pointer: &mut String, done: &mut HashSet<String>, visit: &mut impl FnMut(&mut Value)) {
+    let len = pointer.len();
+    match value {
+        Value::String(_) if done.insert(pointer.clone()) => visit(value),
+        Value::Array(items) => {
+            for (i, item) in items.iter_mut().enumerate() {
+                pointer.push_str(&format!("/{}", i));
+                visit_strings(item, pointer, done, visit);
+                pointer.truncate(len);
+            }
+        },
+        Value::Object(map) => {
+            for (key, item) in map.iter_mut() {
+                pointer.push('/');
+                pointer.push_str(&key.replace('~', "~0").replace('/', "~1"));
+                visit_strings(item, pointer, done, visit);
+                pointer.truncate(len);
+            }
+        },
+        _ => {},
+    }
+}
@@ -1,25 +1,68 @@
 mod hanja_char;
 mod hanja_word;
+mod hanja_info;
+mod hanja_variant;
+mod hanja_simplified;
 mod dueum;
+pub mod dic_format;
+pub mod conversion;
+pub mod lint;
+pub mod access;
+pub mod assets;
+pub mod cache;
+pub mod char_info;
+pub mod search;
+pub mod analysis;
+pub mod html;
+pub mod markdown;
+pub mod subtitle;
+pub mod document;
+pub mod table;
+pub mod json;
+pub mod romanize;
+pub mod pronunciation;
+pub mod compound;
+pub mod server;
+
+// build.rs가 사전 원본으로 만든 정적 perfect-hash 맵: CHAR_TABLE, DUEUM_TABLE, WORD_TABLE, INFO_TABLE, VARIANT_TABLE, TRADITIONAL_TABLE, DICTIONARY_VERSION
+mod tables {
+    include!(concat!(env!("OUT_DIR"), "/dictionary_tables.rs"));
+}
 
 use std::env;
 use std::{collections::HashMap, error::Error};
 use std::sync::Arc;
 
-const KO_START:u32 = 44032;
-const KO_END:u32 = 55203;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
 
-const CHI_S1:u32 = 13312;
-const CHI_E1:u32 = 19903;
+use dic_format::{parse_line, Line};
 
-const CHI_S2:u32 = 19968;
-const CHI_E2:u32 = 40959;
+pub use conversion::{Conversion, ConvertOptions, InputFormat, MappedChar, Normalize, OutputMode, Span, SpanKind};
+pub use dic_format::{EduLevel, Reading};
 
-const CHI_S3:u32 = 63744;
-const CHI_E3:u32 = 64045;
+const KO_START:u32 = 44032;
+const KO_END:u32 = 55203;
 
-const CHI_S4:u32 = 64048;
-const CHI_E4:u32 = 64109;
+// 한자로 보는 유니코드 범위 (Unicode 16.0 기준, 글자가 배정된 범위만)
+const CHI_RANGES:[(u32, u32); 16] = [
+    (0x2E80, 0x2E99),    // CJK Radicals Supplement
+    (0x2E9B, 0x2EF3),
+    (0x2F00, 0x2FD5),    // Kangxi Radicals
+    (0x3400, 0x4DBF),    // CJK Unified Ideographs Extension A
+    (0x4E00, 0x9FFF),    // CJK Unified Ideographs
+    (0xF900, 0xFA6D),    // CJK Compatibility Ideographs
+    (0xFA70, 0xFAD9),
+    (0x20000, 0x2A6DF),  // Extension B
+    (0x2A700, 0x2B739),  // Extension C
+    (0x2B740, 0x2B81D),  // Extension D
+    (0x2B820, 0x2CEA1),  // Extension E
+    (0x2CEB0, 0x2EBE0),  // Extension F
+    (0x2EBF0, 0x2EE5D),  // Extension I
+    (0x2F800, 0x2FA1D),  // CJK Compatibility Ideographs Supplement
+    (0x30000, 0x3134A),  // Extension G
+    (0x31350, 0x323AF),  // Extension H
+];
 
 pub struct Config {
     pub mode: &'static str,
@@ -28,6 +71,22 @@ pub struct Config {
     pub https_redirect: &'static str,
     pub ssl_cert: &'static str,
     pub ssl_key: &'static str,
+    pub default_body_limit: usize,    // 일반 요청 본문 최대 크기(byte)
+    pub convert_body_limit: usize,    // /convert 요청 본문 최대 크기(byte)
+    pub document_body_limit: usize,   // /document(EPUB, DOCX 파일 올리기) 요청 본문 최대 크기(byte)
+    pub request_timeout_secs: u64,    // 요청 처리 제한 시간(초)
+    pub concurrency_limit: usize,     // 동시에 처리할 수 있는 최대 요청 수
+    pub api_keys_file: &'static str,  // ("클라이언트이름,API키") 형태의 API 키 목록 파일
+    pub anon_rate_per_min: u32,       // API 키 없는 요청: IP 당 1분에 허용하는 변환 요청 수
+    pub anon_burst: u32,              // API 키 없는 요청: IP 당 한 번에 몰아서 보낼 수 있는 요청 수
+    pub key_rate_per_min: u32,        // API 키 요청: 키 당 1분에 허용하는 변환 요청 수
+    pub key_burst: u32,               // API 키 요청: 키 당 한 번에 몰아서 보낼 수 있는 요청 수
+    pub cors_allowed_origins: &'static [&'static str],  // 브라우저에서 직접 호출을 허용할 다른 사이트
+    pub cors_allowed_methods: &'static [&'static str],
+    pub cors_allowed_headers: &'static [&'static str],
+    pub frame_ancestors: &'static str,  // 페이지를 <iframe>으로 넣을 수 있는 사이트(CSP frame-ancestors)
+    pub assets_from_disk: bool,       // true면 index.html, css, js를 실행 파일 대신 디스크에서 매번 읽는다.
+    pub conversion_cache_bytes: usize,  // 변환 결과 캐시의 최대 크기(byte)
 }
 
 pub fn get_config() -> Config {
@@ -43,6 +102,22 @@ pub fn get_config() -> Config {
             https_redirect: "https://127.0.0.1:443",
             ssl_cert: "cert_local/cert.pem",
             ssl_key: "cert_local/key.pem",
+            default_body_limit: 64 * 1024,
+            convert_body_limit: 1024 * 1024,
+            document_body_limit: 32 * 1024 * 1024,
+            request_timeout_secs: 30,
+            concurrency_limit: 64,
+            api_keys_file: "api_keys.txt",
+            anon_rate_per_min: 600,
+            anon_burst: 100,
+            key_rate_per_min: 6000,
+            key_burst: 1000,
+            cors_allowed_origins: &["http://localhost:3000", "http://localhost:8080"],
+            cors_allowed_methods: &["GET", "POST"],
+            cors_allowed_headers: &["content-type", "authorization"],
+            frame_ancestors: "'self'",
+            assets_from_disk: true,
+            conversion_cache_bytes: 16 * 1024 * 1024,
         },
         _ => Config {       
             mode: "prod",     
@@ -51,76 +126,160 @@ pub fn get_config() -> Config {
             https_redirect: "https://badang.xyz",
             ssl_cert: "/etc/letsencrypt/live/badang.xyz/fullchain.pem",
             ssl_key: "/etc/letsencrypt/live/badang.xyz/privkey.pem",
+            default_body_limit: 64 * 1024,
+            convert_body_limit: 512 * 1024,
+            document_body_limit: 16 * 1024 * 1024,
+            request_timeout_secs: 10,
+            concurrency_limit: 256,
+            api_keys_file: "/etc/rust_web/api_keys.txt",
+            anon_rate_per_min: 30,
+            anon_burst: 10,
+            key_rate_per_min: 600,
+            key_burst: 100,
+            cors_allowed_origins: &["https://www.badang.xyz"],
+            cors_allowed_methods: &["GET", "POST"],
+            cors_allowed_headers: &["content-type", "authorization"],
+            frame_ancestors: "'self'",
+            assets_from_disk: false,
+            conversion_cache_bytes: 128 * 1024 * 1024,
         },
     }
 }
 
+// 빌드할 때 만든 정적 사전. 서버가 시작할 때 파싱하지 않는다.
 pub struct Dictionary {
-    pub char_dic: HashMap<char, char>,
-    pub dueum_dic: HashMap<char, char>,
-    pub word_dic: HashMap<String, String>,
+    pub char_dic: &'static phf::Map<char, char>,
+    pub dueum_dic: &'static phf::Map<char, char>,
+    pub word_dic: &'static phf::Map<&'static str, &'static str>,
+    pub info_dic: &'static phf::Map<char, HanjaInfo>,
+    pub variant_dic: &'static phf::Map<char, char>,  // 이체자 -> 표준자
+    pub traditional_dic: &'static phf::Map<char, char>,  // 간체자, 신자체 -> 번체자
+    pub index: search::SearchIndex,  // 읽기, 부수, 획수, 급수로 찾기 위한 보조 색인
+    pub version: String,  // 사전 원본 내용의 해시. 사전이 바뀌면 값이 바뀐다.
+}
+
+// 한자 정보 사전(hanja_info.rs)의 한 항목
+#[derive(Debug, Serialize)]
+pub struct HanjaInfo {
+    pub hanja: char,
+    pub readings: &'static [Reading],  // 자주 쓰는 순서
+    pub meaning: &'static str,         // 훈. 읽기가 여럿이면 읽기 순서대로 '/'로 구분한다.
+    pub radical: Option<char>,
+    pub extra_strokes: Option<u8>,     // 부수를 뺀 나머지 획수
+    pub strokes: Option<u8>,           // 총획
+    pub grade: Option<&'static str>,   // 한자능력검정시험 급수
+    pub edu: EduLevel,                 // 교육용 기초한자 구분
+}
+
+impl Dictionary {
+    pub fn info(&self, c: char) -> Option<&'static HanjaInfo> {
+        self.info_dic.get(&c)
+    }
+}
+
+// 한 글자 사전(기본한자, 두음법칙). 실행 중에 읽은 HashMap과 빌드할 때 만든 phf::Map 모두 사용할 수 있다.
+pub trait CharTable {
+    fn get_char(&self, c: &char) -> Option<char>;
+}
+
+// 불규칙 변환 한자사전
+pub trait WordTable {
+    fn get_word(&self, word: &str) -> Option<&str>;
+}
+
+impl CharTable for HashMap<char, char> {
+    fn get_char(&self, c: &char) -> Option<char> {
+        self.get(c).copied()
+    }
+}
+
+impl CharTable for phf::Map<char, char> {
+    fn get_char(&self, c: &char) -> Option<char> {
+        self.get(c).copied()
+    }
 }
 
+impl WordTable for HashMap<String, String> {
+    fn get_word(&self, word: &str) -> Option<&str> {
+        self.get(word).map(|v| v.as_str())
+    }
+}
+
+impl WordTable for phf::Map<&'static str, &'static str> {
+    fn get_word(&self, word: &str) -> Option<&str> {
+        self.get(word).copied()
+    }
+}
+
+pub type DictionaryMaps = (HashMap<char, char>, HashMap<char, char>, HashMap<String, String>);
+
 pub fn load_arc_dictionary() 
         -> Result<Arc<Dictionary>, Box<dyn Error>> {
-    let dic = load_dictionary()?;
     let dic_arc = Arc::new(Dictionary {
-        char_dic: dic.0,
-        dueum_dic: dic.1,
-        word_dic: dic.2,
+        char_dic: &tables::CHAR_TABLE,
+        dueum_dic: &tables::DUEUM_TABLE,
+        word_dic: &tables::WORD_TABLE,
+        info_dic: &tables::INFO_TABLE,
+        variant_dic: &tables::VARIANT_TABLE,
+        traditional_dic: &tables::TRADITIONAL_TABLE,
+        index: search::SearchIndex::build(&tables::CHAR_TABLE, &tables::INFO_TABLE),
+        version: tables::DICTIONARY_VERSION.to_string(),
     });
     Ok(dic_arc)
 }
 
-fn load_dictionary() 
-        -> Result<(HashMap<char, char>, HashMap<char, char>, HashMap<String, String>), Box<dyn Error>> {
+// 여러 조각을 차례로 넣어 만든 SHA-256 해시의 앞 16바이트(16진수 32자리)
+// 조각마다 길이를 먼저 넣으므로 ("ab","c")와 ("a","bc")의 해시는 다르다.
+pub fn digest_hex(parts: &[&[u8]]) -> String {
+    let mut hasher = Sha256::new();
+    for part in parts {
+        hasher.update((part.len() as u64).to_le_bytes());
+        hasher.update(part);
+    }
+    hasher.finalize()[..16].iter().map(|b| format!("{:02x}", b)).collect::<String>()
+}
+
+// 해시로 만든 약한(weak) ETag. 응답은 CompressionLayer가 gzip, br 등으로 압축하므로
+// 압축 방식마다 byte가 달라도 같은 내용이면 같은 태그를 사용한다.
+pub fn weak_etag(hash: &str) -> String {
+    format!("W/\"{}\"", hash)
+}
+
+// If-None-Match 헤더 값에 etag가 있는지 약한 비교(weak comparison)로 확인한다. W/는 무시한다.
+pub fn if_none_match(header_value: &str, etag: &str) -> bool {
+    let opaque = |tag: &str| tag.trim().trim_start_matches("W/").to_string();
+    header_value.split(',').any(|tag| tag.trim() == "*" || opaque(tag) == opaque(etag))
+}
+
+// 사전 원본을 실행 중에 파싱해서 HashMap으로 만든다.
+// 서버는 빌드할 때 만든 정적 사전(load_arc_dictionary)을 사용하고, 이 함수는 비교(benches/dictionary.rs)를 위해 남겨 둔다.
+pub fn load_dictionary() -> Result<DictionaryMaps, Box<dyn Error>> {
     
     //1. 기본한자 변환 사전
-    let char_dic = hanja_char::HANJA_BASIC.lines()
-        .filter_map(|line| {
-            let parts: Vec<&str> = line.split(',').collect();
-            if parts.len() == 2 {
-                let key_char = parts[0].trim().chars().next().unwrap();
-                let val_char = parts[1].trim().chars().next().unwrap();
-                Some((key_char, val_char))
-            } else {
-                None
-            }
-        })
-        .collect::<HashMap<char, char>>();
-
+    // 기존 형식("伽,가")과 구조화 형식(dic_format.rs 참조)을 모두 받는다. 구조화 형식은 첫 번째 읽기를 사용한다.
+    // 한자 정보 사전(HANJA_INFO)의 읽기는 기본한자 사전에 없는 한자에만 사용한다.
+    let mut char_dic = char_entries(hanja_char::HANJA_BASIC).collect::<HashMap<char, char>>();
+    for (key_char, val_char) in char_entries(hanja_info::HANJA_INFO) {
+        char_dic.entry(key_char).or_insert(val_char);
+    }
+    // 사전에 없는 호환용 한자와 부수 글자는 통합 한자의 읽기를 사용한다. (build.rs와 같은 규칙)
+    let derived = dic_format::derived_readings(|c| char_dic.get(&c).copied());
+    char_dic.extend(derived);
 
     //2. 두음법칙 사전
     //dueum::DUEUM은 ("냥,양\n") 형태의 여러 라인으로 구성되어 있다. 
     //모든 라인을 읽고, 각 라인 별로 콤마를 기준으로 split하여, 앞 문자와 뒤 문자를 각각 key와 value로 설정한다.
     //이때, key와 value는 모두 char로 변환하여 저장한다.
-    let dueum_dic = dueum::DUEUM.lines()
-        .filter_map(|line| {
-            let parts: Vec<&str> = line.split(',').collect();
-            if parts.len() == 2 {
-                let key_char = parts[0].trim().chars().next().unwrap();
-                let val_char = parts[1].trim().chars().next().unwrap();
-                Some((key_char, val_char))
-            } else {
-                None
-            }
-        })
-        .collect::<HashMap<char, char>>();
+    let dueum_dic = char_entries(dueum::DUEUM).collect::<HashMap<char, char>>();
 
     //3. 불규칙 변환 한자사전
     // hanja_word::HANJA_SPECIAL은 ("女子,여자\n") 형태의 여러 라인으로 구성되어 있다.
     //모든 라인을 읽고, 각 라인 별로 콤마를 기준으로 split하여, 앞 문자와 뒤 문자를 각각 key와 value로 설정한다.
     //이때, key와 value는 모두 String으로 변환하여 저장한다.
     let word_dic = hanja_word::HANJA_SPECIAL.lines()
-        .filter_map(|line| {
-            let parts: Vec<&str> = line.split(',').collect();
-            if parts.len() == 2 {
-                let key_str = parts[0].trim().to_string();
-                let val_str = parts[1].trim().to_string();
-                Some((key_str, val_str))
-            } else {
-                None
-            }
+        .filter_map(|line| match parse_line(line) {
+            Ok(Line::Pair(key_str, val_str)) => Some((key_str, val_str)),
+            _ => None,
         })
         .collect::<HashMap<String, String>>();  
 
@@ -128,93 +287,215 @@ fn load_dictionary()
 }
 
 
+// 한 글자 사전의 (key, value). 잘못된 줄은 건너뛴다.
+fn char_entries(text: &str) -> impl Iterator<Item = (char, char)> + '_ {
+    text.lines().filter_map(|line| match parse_line(line) {
+        Ok(Line::Pair(key, value)) => Some((key.chars().next()?, value.chars().next()?)),
+        Ok(Line::Record(record)) => Some((record.hanja, record.readings[0].hangul)),
+        _ => None,
+    })
+}
+
 pub async fn convert_str(
     input_str:&str,         
     char_dic:&HashMap<char,char>, 
     dueum_dic:&HashMap<char,char>,
     word_dic:&HashMap<String, String>) -> Option<String>{
 
+    let conversion = convert_spans(input_str, char_dic, dueum_dic, word_dic, &HashMap::<char, char>::new(), &HashMap::<char, char>::new(), &ConvertOptions::default());
+
+    //  if there is no chinese character in the string, return None.
+    //   if exist, return the converted string.
+    if !conversion.is_converted() {return None;}
+    Some(conversion.render(OutputMode::Hangul))
+}
+
+impl Dictionary {
+    pub fn convert(&self, input_str: &str, options: &ConvertOptions) -> Conversion {
+        convert_spans(input_str, self.char_dic, self.dueum_dic, self.word_dic, self.variant_dic, self.traditional_dic, options)
+    }
+
+    // 문서(HTML 등)의 한 부분 input[start..end]를 변환해서 spans에 붙인다. converts가 false이면 그대로 붙인다.
+    // 구간의 위치는 문서 전체에서의 위치로 바꾼다.
+    pub(crate) fn convert_range(&self, spans: &mut Vec<Span>, input: &str, start: usize, end: usize, converts: bool, options: &ConvertOptions) {
+        if start >= end {
+            return;
+        }
+        if !converts {
+            conversion::push_plain(spans, input, start, end);
+            return;
+        }
+        for mut span in self.convert(&input[start..end], options).spans {
+            span.start += start;
+            span.end += start;
+            if span.kind == SpanKind::Text {
+                conversion::push_plain(spans, input, span.start, span.end);
+            } else {
+                spans.push(span);
+            }
+        }
+    }
+}
+
+// 입력 문자열을 구간(span)으로 나누어 변환한다.
+// variant_dic은 정규화(options.normalize)할 때 사용하는 이체자 -> 표준자 사전,
+// traditional_dic은 options.traditional일 때 사용하는 간체자/신자체 -> 번체자 사전이다.
+pub fn convert_spans<C, D, W, V, T>(
+    input_str:&str,
+    char_dic:&C,
+    dueum_dic:&D,
+    word_dic:&W,
+    variant_dic:&V,
+    traditional_dic:&T,
+    options:&ConvertOptions) -> Conversion
+    where C: CharTable + ?Sized, D: CharTable + ?Sized, W: WordTable + ?Sized, V: CharTable + ?Sized, T: CharTable + ?Sized {
+
+    // 간체자, 신자체는 번체자로 바꾼다.
+    let to_traditional = |c: char| -> char {
+        if !options.traditional {
+            return c;
+        }
+        traditional_dic.get_char(&c).unwrap_or(c)
+    };
+    // 그 다음에 호환용 한자는 통합 한자로, 이체자는 표준자로 바꾼다.
+    let normalize = options.normalize != Normalize::Off;
+    let fold = |c: char| -> char {
+        let c = to_traditional(c);
+        if !normalize {
+            return c;
+        }
+        let unified = char_info::canonical_form(c).unwrap_or(c);
+        variant_dic.get_char(&unified).unwrap_or(unified)
+    };
+
+    // the reading of c itself (or its traditional form) comes first. (compatibility ideographs have their own readings: 樂 U+F914 낙)
+    // simplified forms are read as their traditional forms, even if HANJA_BASIC has another reading (体 분 -> 體 체).
+    let read_char = |c: char| -> Option<char> {
+        char_dic.get_char(&to_traditional(c)).or_else(|| char_dic.get_char(&fold(c)))
+    };
+
     //1. obtain char array from input_str
-    let mut c_iter = input_str.chars().peekable();           
+    let mut c_iter = input_str.char_indices().peekable();
 
     // 2. convert to hangul 
-    let mut buf:String = String::new(); 
-    let mut is_exist_chi:bool = false;     
+    let mut spans:Vec<Span> = Vec::new();
     loop {    
         //2.1 pick a word only contains chinese character
+        //    'folded' is the normalized word. it is the same as 'word' if normalization is off.
         let mut word:String = String::new();   
+        let mut folded:String = String::new();
         let mut tmp_iter = c_iter.clone();
-        while let Some(c) = tmp_iter.peek() {
-            if is_chi(&c) {word.push(*c); tmp_iter.next();}
+        while let Some((_, c)) = tmp_iter.peek() {
+            if is_chi(c) {word.push(*c); folded.push(fold(*c)); tmp_iter.next();}
             else {break;}
         }
 
         //2.2 if 'word' is not empty, check whether it is in the word_dic or not.
-        //    if exist, append the value to w_buf and continue.
-        //    if not, revert the c_iter and continue.
-        if word.len() > 0 {
-            match word_dic.get(&word) {
-                Some(val) => {
-                    buf.push_str(val); 
-                    is_exist_chi = true; 
-                    c_iter = tmp_iter; // Move the main iterator forward
-                    continue;
-                },
-                None => {}
-            }
+        //    if not, check whether it is a compound word of a reading rule(compound.rs). (the dictionary comes first)
+        //    dueum is applied to its first char as to a single char followed by hanja.
+        //    if exist, append the reading to spans and continue.
+        //    if not, fall through and convert one char.
+        let start = c_iter.peek().map_or(input_str.len(), |(i, _)| *i);
+        let matched = if word.is_empty() { None } else {
+            word_dic.get_word(&folded).map(|val| (val.to_string(), None)).or_else(|| {
+                let rule = compound::find(&folded)?;
+                let mut readings = word.chars().enumerate()
+                    .map(|(i, c)| {
+                        let reading = read_char(c)?;
+                        let dueum = if options.dueum && i == 0 { dueum_dic.get_char(&reading) } else { None };
+                        Some(dueum.unwrap_or(reading))
+                    })
+                    .collect::<Option<Vec<char>>>()?;
+                rule.apply(&mut readings);
+                Some((readings.into_iter().collect(), Some(rule)))
+            })
+        };
+        if let Some((val, rule)) = matched {
+            spans.push(Span {
+                start,
+                end: start + word.len(),
+                normalized: (folded != word).then_some(folded),
+                source: word,
+                reading: val,
+                kind: if rule.is_some() { SpanKind::Rule } else { SpanKind::Word },
+                rule,
+            });
+            c_iter = tmp_iter; // Move the main iterator forward
+            continue;
         }
         
         //2.3 pick a char. if c is None, it's end of file
-        let c = match c_iter.next() { 
+        let (start, c) = match c_iter.next() { 
             Some(ch) => {ch},  None => {break;} 
         };
-        let mut new_c = c.clone();
-
-        //2.4 if hanja then convert to hangul else not change       
-        if is_chi(&c) {         
-            match char_dic.get(&c) {
-                Some(val) => {new_c = *val; is_exist_chi = true; },  
-                None => {},
-            };          
-
-            //2.5. dueum law(두음법칙)
-            if let Some(c_peek) = c_iter.peek(){                
-                if is_kor_or_chi(&c_peek) { // if next char is exist
-                    match dueum_dic.get(&new_c) {
-                        Some(ch) => {new_c = *ch;},
-                        None => {},
+
+        //2.4 if not hanja then not change. consecutive characters are merged into one span.
+        if !is_chi(&c) {
+            match spans.last_mut() {
+                Some(last) if last.kind == SpanKind::Text => {
+                    last.source.push(c);
+                    last.reading.push(c);
+                    last.end += c.len_utf8();
+                },
+                _ => spans.push(Span {
+                    start,
+                    end: start + c.len_utf8(),
+                    source: c.to_string(),
+                    reading: c.to_string(),
+                    kind: SpanKind::Text,
+                    normalized: None,
+                    rule: None,
+                }),
+            }
+            continue;
+        }
+
+        //2.5 if hanja then convert to hangul
+        let folded_c = fold(c);
+        let (mut new_c, kind) = match read_char(c) {
+            Some(val) => (val, SpanKind::Char),
+            None => (c, SpanKind::Unknown),
+        };
+
+        //2.6. dueum law(두음법칙)
+        if options.dueum {
+            if let Some((_, c_peek)) = c_iter.peek(){                
+                if is_kor_or_chi(c_peek) { // if next char is exist
+                    if let Some(ch) = dueum_dic.get_char(&new_c) {
+                        new_c = ch;
                     }
                 }                     
             }          
         }
-        buf.push(new_c);
+
+        spans.push(Span {
+            start,
+            end: start + c.len_utf8(),
+            source: c.to_string(),
+            reading: new_c.to_string(),
+            kind,
+            normalized: (folded_c != c).then(|| folded_c.to_string()),
+            rule: None,
+        });
     }
     
-    //  if there is no chinese character in the string, return None.
-    //   if exist, return the converted string.
-    if !is_exist_chi {return None;}
-    Some(buf)        
+    Conversion { spans, rewrite: options.normalize == Normalize::Rewrite }
 }    
 
 // whether c is chinese character or not
-fn is_chi(c:&char) -> bool {
+pub fn is_chi(c:&char) -> bool {
     let n = *c as u32;
-    if  (n >= CHI_S1 && n <= CHI_E1) || (n >= CHI_S2 && n <= CHI_E2) || 
-        (n >= CHI_S3 && n <= CHI_E3) || (n >= CHI_S4 && n <= CHI_E4) { 
-        true 
-    }else { false }  
+    CHI_RANGES.iter().any(|(start, end)| (*start..=*end).contains(&n))
+}
+
+// whether c is korean syllable or not
+fn is_hangul(c:&char) -> bool {
+    (KO_START..=KO_END).contains(&(*c as u32))
 }
 
 // whether c is (korean or chinese character) or not
 fn is_kor_or_chi(c:&char) -> bool {
-    let n = *c as u32;
-    if (n >= KO_START && n <= KO_END) || 
-       ( (n >= CHI_S1 && n <= CHI_E1) || (n >= CHI_S2 && n <= CHI_E2) || 
-         (n >= CHI_S3 && n <= CHI_E3) || (n >= CHI_S4 && n <= CHI_E4)) {
-        true
-    }else {
-        false
-    }    
+    is_hangul(c) || is_chi(c)
 }
 
 
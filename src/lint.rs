@@ -0,0 +1,310 @@
+// 사전 검사 도구
+// 사전을 읽을 때(load_dictionary, build.rs)는 형식이 잘못된 줄을 조용히 건너뛰고, 같은 key가 여러 번 나오면 뒤의 값으로 덮어쓴다.
+// 이 모듈은 그런 문제를 줄 번호와 함께 보고한다. 줄 번호는 각 사전 문자열의 첫 줄을 1로 세고,
+// validate_dictionaries는 사전 원본 파일의 줄 번호로 바꾸어 돌려준다.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::dic_format::{parse_line, Line, Record};
+use crate::{convert_spans, dueum, hanja_char, hanja_info, hanja_simplified, hanja_variant, hanja_word, is_chi, is_hangul, ConvertOptions, OutputMode};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone)]
+pub struct Issue {
+    pub dictionary: &'static str,
+    pub file: Option<&'static str>,  // 사전 원본 파일. 있으면 line은 파일의 줄 번호이다.
+    pub line: usize,
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let severity = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        write!(f, "{}:{}: {}: {}", self.file.unwrap_or(self.dictionary), self.line, severity, self.message)
+    }
+}
+
+// 사전 한 줄을 key, value로 나눈 것. 구조화 형식의 줄은 첫 번째 읽기를 value로 본다.
+struct Entry {
+    line: usize,
+    key: String,
+    value: String,
+    record: Option<Record>,
+}
+
+// 기본한자 사전, 한자 정보 사전, 두음법칙 사전, 불규칙 변환 한자사전, 이체자 사전, 간체자/신자체 사전을 모두 검사한다.
+pub fn validate_dictionaries() -> Vec<Issue> {
+    let mut issues = Vec::new();
+    issues.extend(validate_char_dic("HANJA_BASIC", hanja_char::HANJA_BASIC));
+    issues.extend(validate_info_dic("HANJA_INFO", hanja_info::HANJA_INFO, hanja_char::HANJA_BASIC));
+    issues.extend(validate_dueum_dic("DUEUM", dueum::DUEUM));
+    issues.extend(validate_word_dic("HANJA_SPECIAL", hanja_word::HANJA_SPECIAL, hanja_char::HANJA_BASIC, dueum::DUEUM));
+    issues.extend(validate_variant_dic("HANJA_VARIANT", hanja_variant::HANJA_VARIANT, hanja_char::HANJA_BASIC));
+    issues.extend(validate_traditional_dic("HANJA_SIMPLIFIED", hanja_simplified::HANJA_SIMPLIFIED, hanja_char::HANJA_BASIC));
+    for issue in &mut issues {
+        locate(issue);
+    }
+    issues
+}
+
+// (사전 이름, 원본 파일, 파일 내용)
+const SOURCES: &[(&str, &str, &str)] = &[
+    ("HANJA_BASIC", "src/hanja_char.rs", include_str!("hanja_char.rs")),
+    ("HANJA_INFO", "src/hanja_info.rs", include_str!("hanja_info.rs")),
+    ("DUEUM", "src/dueum.rs", include_str!("dueum.rs")),
+    ("HANJA_SPECIAL", "src/hanja_word.rs", include_str!("hanja_word.rs")),
+    ("HANJA_VARIANT", "src/hanja_variant.rs", include_str!("hanja_variant.rs")),
+    ("HANJA_SIMPLIFIED", "src/hanja_simplified.rs", include_str!("hanja_simplified.rs")),
+];
+
+// 사전 문자열 안의 줄 번호를 원본 파일의 줄 번호로 바꾼다.
+fn locate(issue: &mut Issue) {
+    let Some((_, file, source)) = SOURCES.iter().find(|(name, _, _)| *name == issue.dictionary) else { return };
+    if let Some(first_line) = literal_first_line(source, issue.dictionary) {
+        issue.file = Some(file);
+        issue.line += first_line - 1;
+    }
+}
+
+// 파일에서 `pub const name` 선언 다음에 문자열(r#")이 시작하는 줄 번호(1부터). 문자열의 첫 줄이 이 줄이다.
+pub fn literal_first_line(source: &str, name: &str) -> Option<usize> {
+    let declaration = format!("pub const {}:", name);
+    source.lines().enumerate()
+        .skip_while(|(_, line)| !line.starts_with(&declaration))
+        .find(|(_, line)| line.contains("r#\""))
+        .map(|(i, _)| i + 1)
+}
+
+// ("한자,한글") 형태 또는 구조화 형식: key는 한자 한 글자, value(읽기)는 한글 한 음절
+pub fn validate_char_dic(name: &'static str, text: &str) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    let entries = split_entries(name, text, true, &mut issues);
+    for entry in &entries {
+        check_char_entry(name, entry, &mut issues);
+    }
+    check_duplicates(name, &entries, &mut issues);
+    issues
+}
+
+// 한자 정보 사전: 기본한자 사전과 같은 검사에 더해, 획수와 기본한자 사전의 읽기를 확인한다.
+pub fn validate_info_dic(name: &'static str, text: &str, char_text: &str) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    let entries = split_entries(name, text, true, &mut issues);
+    let char_dic = to_char_map(char_text);
+    for entry in &entries {
+        check_char_entry(name, entry, &mut issues);
+        let Some(record) = &entry.record else { continue };
+
+        if let (Some(extra), Some(strokes)) = (record.extra_strokes, record.strokes) {
+            if extra >= strokes && strokes > 0 && extra > 0 {
+                issues.push(issue(name, entry.line, Severity::Error,
+                    format!("{}: 부수를 뺀 나머지 획수({})가 총획({})보다 작지 않습니다.", record.hanja, extra, strokes)));
+            }
+        }
+        if let Some(basic) = char_dic.get(&record.hanja) {
+            if !record.readings.iter().any(|r| r.hangul == *basic) {
+                issues.push(issue(name, entry.line, Severity::Warning,
+                    format!("{}: 기본한자 사전의 읽기 {:?}가 읽기 목록에 없습니다.", record.hanja, basic)));
+            }
+        }
+        let mut ranks = record.readings.iter().map(|r| r.rank).collect::<Vec<u8>>();
+        ranks.dedup();
+        if ranks.len() != record.readings.len() {
+            issues.push(issue(name, entry.line, Severity::Warning,
+                format!("{}: 읽기 순위가 중복됩니다.", record.hanja)));
+        }
+    }
+    check_duplicates(name, &entries, &mut issues);
+    issues
+}
+
+// ("한글,한글") 형태: key와 value 모두 한글 한 음절
+pub fn validate_dueum_dic(name: &'static str, text: &str) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    let entries = split_entries(name, text, false, &mut issues);
+    for entry in &entries {
+        check_single(name, entry.line, "key", &entry.key, is_hangul, "한글 음절", &mut issues);
+        check_single(name, entry.line, "value", &entry.value, is_hangul, "한글 음절", &mut issues);
+    }
+    check_duplicates(name, &entries, &mut issues);
+    issues
+}
+
+// ("한자단어,한글단어") 형태: key는 한자로만, value는 한글 음절로만 이루어져야 한다.
+// 사전 없이 한 글자씩 변환한 결과와 같은 단어는 불규칙 변환 사전에 있을 필요가 없다.
+pub fn validate_word_dic(name: &'static str, text: &str, char_text: &str, dueum_text: &str) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    let entries = split_entries(name, text, false, &mut issues);
+
+    let char_dic = to_char_map(char_text);
+    let dueum_dic = to_char_map(dueum_text);
+    let no_words: HashMap<String, String> = HashMap::new();
+    let no_chars: HashMap<char, char> = HashMap::new();
+
+    for entry in &entries {
+        if let Some(c) = entry.key.chars().find(|c| !is_chi(c)) {
+            issues.push(issue(name, entry.line, Severity::Error,
+                format!("key {:?}에 한자가 아닌 문자 {:?}(U+{:04X})가 있습니다.", entry.key, c, c as u32)));
+        }
+        if let Some(c) = entry.value.chars().find(|c| !is_hangul(c)) {
+            issues.push(issue(name, entry.line, Severity::Error,
+                format!("value {:?}에 한글 음절이 아닌 문자 {:?}(U+{:04X})가 있습니다.", entry.value, c, c as u32)));
+        }
+        if entry.key.chars().count() != entry.value.chars().count() {
+            issues.push(issue(name, entry.line, Severity::Warning,
+                format!("key {:?}와 value {:?}의 글자 수가 다릅니다.", entry.key, entry.value)));
+        }
+
+        let plain = convert_spans(&entry.key, &char_dic, &dueum_dic, &no_words, &no_chars, &no_chars, &ConvertOptions::default())
+            .render(OutputMode::Hangul);
+        if plain == entry.value {
+            issues.push(issue(name, entry.line, Severity::Warning,
+                format!("{},{}: 한 글자씩 변환한 결과와 같으므로 없어도 됩니다.", entry.key, entry.value)));
+        }
+    }
+    check_duplicates(name, &entries, &mut issues);
+    issues
+}
+
+// ("이체자,표준자") 형태: key와 value 모두 한자 한 글자
+// 이체자와 표준자의 읽기가 다르면 다른 글자일 수 있으므로 경고한다.
+pub fn validate_variant_dic(name: &'static str, text: &str, char_text: &str) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    let entries = split_entries(name, text, false, &mut issues);
+    let char_dic = to_char_map(char_text);
+    check_mapping(name, &entries, &mut issues);
+    for entry in &entries {
+        let reading = |s: &str| s.chars().next().and_then(|c| char_dic.get(&c).copied());
+        if let (Some(a), Some(b)) = (reading(&entry.key), reading(&entry.value)) {
+            if a != b {
+                issues.push(issue(name, entry.line, Severity::Warning,
+                    format!("{},{}: 이체자의 읽기 {:?}와 표준자의 읽기 {:?}가 다릅니다.", entry.key, entry.value, a, b)));
+            }
+        }
+    }
+    check_duplicates(name, &entries, &mut issues);
+    issues
+}
+
+// ("간체자/신자체,번체자") 형태: key와 value 모두 한자 한 글자
+// 간체자는 국내에서 다른 읽기로 쓰는 경우가 있으므로 읽기는 비교하지 않고, 번체자의 읽기가 있는지만 확인한다.
+pub fn validate_traditional_dic(name: &'static str, text: &str, char_text: &str) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    let entries = split_entries(name, text, false, &mut issues);
+    let char_dic = to_char_map(char_text);
+    check_mapping(name, &entries, &mut issues);
+    for entry in &entries {
+        if entry.value.chars().next().is_some_and(|c| !char_dic.contains_key(&c)) {
+            issues.push(issue(name, entry.line, Severity::Error,
+                format!("번체자 {:?}가 기본한자 사전에 없습니다.", entry.value)));
+        }
+    }
+    check_duplicates(name, &entries, &mut issues);
+    issues
+}
+
+// 한 글자 -> 한 글자 매핑 사전(이체자, 간체자)의 공통 검사
+// 바꾼 글자가 다시 key로 등록되어 있으면 한 번에 바꿀 수 없으므로 오류로 본다.
+fn check_mapping(name: &'static str, entries: &[Entry], issues: &mut Vec<Issue>) {
+    let keys = entries.iter().map(|entry| entry.key.as_str()).collect::<Vec<&str>>();
+    for entry in entries {
+        check_single(name, entry.line, "key", &entry.key, is_chi, "한자", issues);
+        check_single(name, entry.line, "value", &entry.value, is_chi, "한자", issues);
+        if entry.key == entry.value {
+            issues.push(issue(name, entry.line, Severity::Error,
+                format!("key와 value {:?}가 같습니다.", entry.key)));
+        } else if keys.contains(&entry.value.as_str()) {
+            issues.push(issue(name, entry.line, Severity::Error,
+                format!("value {:?}가 다른 줄에서 key로 등록되어 있습니다.", entry.value)));
+        }
+    }
+}
+
+// 형식이 잘못된 줄(콤마가 정확히 하나가 아니거나, key 또는 value가 비어 있는 줄 등)을 보고하고 나머지 줄을 돌려준다.
+// allow_record가 false이면 구조화 형식의 줄도 오류로 본다.
+fn split_entries(name: &'static str, text: &str, allow_record: bool, issues: &mut Vec<Issue>) -> Vec<Entry> {
+    let mut entries = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        let line_no = i + 1;
+        match parse_line(line) {
+            Ok(Line::Blank) => {},
+            Ok(Line::Pair(key, value)) => entries.push(Entry { line: line_no, key, value, record: None }),
+            Ok(Line::Record(record)) if allow_record => entries.push(Entry {
+                line: line_no,
+                key: record.hanja.to_string(),
+                value: record.readings[0].hangul.to_string(),
+                record: Some(record),
+            }),
+            Ok(Line::Record(_)) => issues.push(issue(name, line_no, Severity::Error,
+                format!("이 사전에는 구조화 형식(탭으로 구분)을 쓸 수 없습니다: {:?}", line))),
+            Err(msg) => issues.push(issue(name, line_no, Severity::Error, format!("{}: {:?}", msg, line))),
+        }
+    }
+    entries
+}
+
+// 한 글자 사전의 key는 한자, 읽기는 모두 한글 음절이어야 한다.
+fn check_char_entry(name: &'static str, entry: &Entry, issues: &mut Vec<Issue>) {
+    check_single(name, entry.line, "key", &entry.key, is_chi, "한자", issues);
+    match &entry.record {
+        Some(record) => {
+            for reading in &record.readings {
+                check_single(name, entry.line, "읽기", &reading.hangul.to_string(), is_hangul, "한글 음절", issues);
+            }
+        },
+        None => check_single(name, entry.line, "value", &entry.value, is_hangul, "한글 음절", issues),
+    }
+}
+
+// 한 글자여야 하는 필드를 검사한다.
+fn check_single(
+        name: &'static str, line: usize, field: &str, value: &str,
+        is_valid: fn(&char) -> bool, expected: &str, issues: &mut Vec<Issue>) {
+    let mut chars = value.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if is_valid(&c) => {},
+        (Some(c), None) => issues.push(issue(name, line, Severity::Error,
+            format!("{} {:?}(U+{:04X})는 {}가 아닙니다.", field, value, c as u32, expected))),
+        _ => issues.push(issue(name, line, Severity::Error,
+            format!("{} {:?}는 {} 한 글자가 아닙니다.", field, value, expected))),
+    }
+}
+
+// 같은 key가 여러 번 나오면 보고한다. value가 다르면 뒤의 값이 앞의 값을 덮어쓰므로 오류로 본다.
+fn check_duplicates(name: &'static str, entries: &[Entry], issues: &mut Vec<Issue>) {
+    let mut first: HashMap<&str, &Entry> = HashMap::new();
+    for entry in entries {
+        match first.get(entry.key.as_str()) {
+            Some(prev) if prev.value == entry.value => {
+                issues.push(issue(name, entry.line, Severity::Warning,
+                    format!("key {:?}가 {}번째 줄과 중복됩니다.", entry.key, prev.line)));
+            },
+            Some(prev) => {
+                issues.push(issue(name, entry.line, Severity::Error,
+                    format!("key {:?}의 값 {:?}가 {}번째 줄의 값 {:?}와 다릅니다(뒤의 값으로 덮어씀).",
+                        entry.key, entry.value, prev.line, prev.value)));
+            },
+            None => {
+                first.insert(&entry.key, entry);
+            }
+        }
+    }
+}
+
+fn to_char_map(text: &str) -> HashMap<char, char> {
+    crate::char_entries(text).collect::<HashMap<char, char>>()
+}
+
+fn issue(dictionary: &'static str, line: usize, severity: Severity, message: String) -> Issue {
+    Issue { dictionary, file: None, line, severity, message }
+}
@@ -2,60 +2,513 @@
 use std::sync::Arc;
 
 use axum::{
-    extract::Json as ExtractJson, response::{Html, IntoResponse, Redirect}, routing::{get, post}, Json, Router
+    extract::{ConnectInfo, DefaultBodyLimit, Json as ExtractJson, Multipart, Path, Query, Request, State},
+    http::{header, HeaderMap, HeaderValue, StatusCode, Uri}, middleware::{self, Next}, response::{IntoResponse, Redirect, Response},
+    routing::{get, post}, Json, Router
 };
 use axum_server::tls_rustls::RustlsConfig;
 use std::net::SocketAddr;
 
 use serde::{Deserialize, Serialize};
-use tower_http::services::ServeDir;
+use tower_http::set_header::SetResponseHeaderLayer;
+
+use rust_web::access::{AccessControl, ApiKeys, Denied, RateLimiter};
+use rust_web::assets::Assets;
+use rust_web::cache::{CacheStats, CachedConversion, ConversionCache};
+use rust_web::document::DocumentKind;
+use rust_web::analysis::ReportFormat;
+use rust_web::html::HtmlOptions;
+use rust_web::search::SearchQuery;
+use rust_web::server::with_common_layers;
+use rust_web::subtitle::{SubtitleFormat, DEFAULT_MAX_WIDTH};
+use rust_web::pronunciation::Pronunciation;
+use rust_web::romanize::Romanization;
+use rust_web::table::{TableFormat, TableOptions};
+use rust_web::{ConvertOptions, InputFormat, Normalize, OutputMode};
+
+mod cli;
 
 #[derive(Deserialize)]
 struct InputData {
     text: String,
+    #[serde(default)]
+    format: InputFormat,  // text(기본), html, markdown, srt, vtt, csv, tsv
+    #[serde(flatten)]
+    options: ConvertOptions,  // mode, dueum, normalize, traditional
+    #[serde(flatten)]
+    html: HtmlOptions,  // skip_elements
+    #[serde(flatten)]
+    table: TableOptions,  // columns, header, append, report
+    #[serde(default)]
+    romanize: Option<Romanization>,  // rr, mr. text, html, markdown 형식에서만 사용할 수 있다.
+    #[serde(default)]
+    pronunciation: bool,  // 한자 단어마다 표준 발음을 돌려줄지. romanize와 같은 형식에서만 사용할 수 있다.
+}
+
+#[derive(Deserialize)]
+struct JsonInput {
+    document: serde_json::Value,
+    #[serde(default)]
+    paths: Vec<String>,  // JSONPath 또는 JSON Pointer. 없으면 모든 문자열을 변환한다.
+    #[serde(flatten)]
+    options: ConvertOptions,  // mode, dueum, normalize, traditional
+}
+
+#[derive(Deserialize)]
+struct AnalyzeInput {
+    text: String,
+    #[serde(default)]
+    format: ReportFormat,  // json(기본), csv
+    #[serde(flatten)]
+    options: ConvertOptions,  // dueum
+}
+
+// GET /convert의 쿼리 문자열. text 외에는 없으면 기본값
+#[derive(Deserialize)]
+struct ConvertQuery {
+    text: String,
+    format: Option<InputFormat>,  // text(기본), html, markdown. 표 형식의 열 지정 등은 POST로 보낸다.
+    mode: Option<OutputMode>,
+    dueum: Option<bool>,
+    normalize: Option<Normalize>,
+    traditional: Option<bool>,
+    romanize: Option<Romanization>,
+    pronunciation: Option<bool>,
+}
+
+// 자막 파일 올리기: 본문은 자막 파일, 옵션은 쿼리 문자열
+#[derive(Deserialize)]
+struct SubtitleQuery {
+    format: Option<SubtitleFormat>,  // 없으면 내용으로 판단한다.
+    mode: Option<OutputMode>,
+    dueum: Option<bool>,
+    normalize: Option<Normalize>,
+    traditional: Option<bool>,
+    max_width: Option<usize>,  // 한 줄의 최대 너비 (한글, 한자 2칸)
+}
+
+// 표 파일 올리기: 본문은 CSV/TSV 파일, 옵션은 쿼리 문자열
+#[derive(Deserialize)]
+struct TableQuery {
+    format: Option<TableFormat>,  // 없으면 csv
+    columns: String,  // 변환할 열의 이름 또는 번호(1부터). 콤마로 구분
+    header: Option<bool>,
+    append: Option<bool>,
+    report: Option<bool>,
+    mode: Option<OutputMode>,
+    dueum: Option<bool>,
+    normalize: Option<Normalize>,
+    traditional: Option<bool>,
 }
 
 #[derive(Serialize)]
 struct OutputData {
     converted_text: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    mapped: Vec<rust_web::MappedChar>,  // 간체자 -> 번체자 등 변환하기 전에 바꾼 한자
+    #[serde(skip_serializing_if = "Option::is_none")]
+    romanized: Option<String>,  // 한글로 변환한 결과의 로마자 표기
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pronunciations: Vec<Pronunciation>,  // 한자 단어마다 철자와 표준 발음
 }
 
 
-async fn hello_rust() -> impl IntoResponse {
-    let path = std::path::PathBuf::from("index.html");
-    let html = match tokio::fs::read_to_string(&path).await {
-        Ok(html) => html,
-        Err(e) => {
-            log::error!("Unable to read file: {}", e);
-            return Html("Error reading file".to_string());
-        }
-    };    
-    Html(html)
+// 기본 웹 페이지(index.html)
+async fn hello_rust(State(assets): State<Arc<Assets>>, headers: HeaderMap) -> Response {
+    serve_asset(&assets, "index.html", &headers).await
+}
+
+// /css, /js 아래의 파일
+async fn static_file(State(assets): State<Arc<Assets>>, uri: Uri, headers: HeaderMap) -> Response {
+    let path = uri.path().trim_start_matches('/');
+    serve_asset(&assets, path, &headers).await
+}
+
+// ETag가 같으면 304를 돌려주고, 아니면 Content-Type, ETag, Cache-Control과 함께 파일 내용을 돌려준다.
+async fn serve_asset(assets: &Assets, path: &str, headers: &HeaderMap) -> Response {
+    let asset = match assets.get(path).await {
+        Some(asset) => asset,
+        None => return (StatusCode::NOT_FOUND, "Not Found").into_response(),
+    };
+
+    // index.html은 항상 다시 확인하게 하고, css/js는 1시간 동안 캐시한다.
+    let cache_control = if assets.from_disk() || path == "index.html" {
+        "no-cache"
+    } else {
+        "public, max-age=3600"
+    };
+
+    if etag_matches(headers, &asset.etag) {
+        return (
+            StatusCode::NOT_MODIFIED,
+            [(header::ETAG, asset.etag.clone()), (header::CACHE_CONTROL, cache_control.to_string())],
+        ).into_response();
+    }
+
+    (
+        [
+            (header::CONTENT_TYPE, asset.content_type.to_string()),
+            (header::ETAG, asset.etag.clone()),
+            (header::CACHE_CONTROL, cache_control.to_string()),
+        ],
+        asset.body.to_vec(),
+    ).into_response()
 }
 
 async fn convert_handler(
         ExtractJson(payload): ExtractJson<InputData>,
-        dic: Arc<rust_web::Dictionary>) -> impl IntoResponse {    
-    match rust_web::convert_str(&payload.text, &dic.char_dic, &dic.dueum_dic, &dic.word_dic).await {
+        dic: Arc<rust_web::Dictionary>,
+        cache: Arc<ConversionCache>) -> Response {    
+    // POST 요청이므로 If-None-Match가 맞아도 304를 돌려주지 않는다(RFC 9110 13.1.2).
+    convert_response(&payload, &dic, &cache, None)
+}
+
+// GET /convert: 옵션을 쿼리 문자열로 받는다. If-None-Match가 ETag와 맞으면 304를 돌려준다.
+async fn convert_get_handler(
+        headers: HeaderMap,
+        Query(query): Query<ConvertQuery>,
+        dic: Arc<rust_web::Dictionary>,
+        cache: Arc<ConversionCache>) -> Response {
+    let defaults = ConvertOptions::default();
+    let payload = InputData {
+        text: query.text,
+        format: query.format.unwrap_or_default(),
+        options: ConvertOptions {
+            mode: query.mode.unwrap_or(defaults.mode),
+            dueum: query.dueum.unwrap_or(defaults.dueum),
+            normalize: query.normalize.unwrap_or(defaults.normalize),
+            traditional: query.traditional.unwrap_or(defaults.traditional),
+        },
+        html: HtmlOptions::default(),
+        table: TableOptions::default(),
+        romanize: query.romanize,
+        pronunciation: query.pronunciation.unwrap_or(false),
+    };
+    convert_response(&payload, &dic, &cache, Some(&headers))
+}
+
+// 입력, 옵션, 사전이 같으면 결과도 같으므로 같은 해시로 ETag와 변환 캐시의 key를 만든다.
+// headers가 있으면(GET 요청) If-None-Match를 확인해서 변환하지 않고 304를 돌려준다.
+fn convert_response(payload: &InputData, dic: &rust_web::Dictionary, cache: &ConversionCache, headers: Option<&HeaderMap>) -> Response {
+    let format = format!("{:?}", payload.format);
+    let romanize = format!("{:?} {}", payload.romanize, payload.pronunciation);
+    let key = rust_web::digest_hex(&[payload.text.as_bytes(), format.as_bytes(), payload.options.key().as_bytes(), payload.html.key().as_bytes(), payload.table.key().as_bytes(), romanize.as_bytes(), dic.version.as_bytes()]);
+    let etag = rust_web::weak_etag(&key);
+    if headers.is_some_and(|headers| etag_matches(headers, &etag)) {
+        return (StatusCode::NOT_MODIFIED, [(header::ETAG, etag)]).into_response();
+    }
+
+    let cached = match cache.get(&dic.version, &key) {
+        Some(cached) => cached,
+        None => {
+            let cached = match convert_payload(payload, dic) {
+                Ok(cached) => cached,
+                Err(msg) => return (StatusCode::BAD_REQUEST, msg).into_response(),
+            };
+            cache.insert(&dic.version, key, cached.clone());
+            cached
+        }
+    };
+
+    let response = match cached.converted_text {
         Some(converted_text) => {
-            let response = OutputData {
+            OutputData {
                 converted_text,
-            };
-            return Json(response);
+                mapped: cached.mapped,
+                romanized: cached.romanized,
+                pronunciations: cached.pronunciations,
+            }
         },
         None => {
-            // 변환할 수 없는 경우
-            let response = OutputData {
+            // 변환할 수 없는 경우. 한자가 없어도 한글은 로마자로 적을 수 있다.
+            OutputData {
                 converted_text: "변환할 수 없습니다.".to_string(),
-            };
-            return Json(response);
+                mapped: Vec::new(),
+                romanized: cached.romanized,
+                pronunciations: Vec::new(),
+            }
+        }
+    };
+    ([(header::ETAG, etag)], Json(response)).into_response()
+}
+
+// 입력 형식에 맞추어 변환한다. 변환할 한자가 없으면 converted_text가 None
+fn convert_payload(payload: &InputData, dic: &rust_web::Dictionary) -> Result<CachedConversion, String> {
+    if (payload.romanize.is_some() || payload.pronunciation) && !matches!(payload.format, InputFormat::Text | InputFormat::Html | InputFormat::Markdown) {
+        return Err("romanize, pronunciation은 text, html, markdown 형식에서만 사용할 수 있습니다.".to_string());
+    }
+    let conversion = match payload.format {
+        InputFormat::Text => dic.convert(&payload.text, &payload.options),
+        InputFormat::Html => dic.convert_html(&payload.text, &payload.options, &payload.html),
+        InputFormat::Markdown => dic.convert_markdown(&payload.text, &payload.options),
+        InputFormat::Srt | InputFormat::Vtt => {
+            let format = if payload.format == InputFormat::Srt { SubtitleFormat::Srt } else { SubtitleFormat::Vtt };
+            let result = dic.convert_subtitle(&payload.text, format, &payload.options, DEFAULT_MAX_WIDTH)?;
+            return Ok(CachedConversion { converted_text: result.converted.then_some(result.subtitle), mapped: result.mapped, romanized: None, pronunciations: Vec::new() });
+        },
+        InputFormat::Csv | InputFormat::Tsv => {
+            let format = if payload.format == InputFormat::Csv { TableFormat::Csv } else { TableFormat::Tsv };
+            let result = dic.convert_table(&payload.text, format, &payload.table, &payload.options)?;
+            return Ok(CachedConversion { converted_text: result.converted.then_some(result.table), mapped: result.mapped, romanized: None, pronunciations: Vec::new() });
+        },
+    };
+    let converted_text = if conversion.is_converted() {
+        Some(conversion.render(payload.options.mode))
+    } else {
+        None
+    };
+    let romanized = payload.romanize.map(|system| conversion.romanize(system));
+    let pronunciations = if payload.pronunciation { conversion.pronunciations() } else { Vec::new() };
+    Ok(CachedConversion { converted_text, mapped: conversion.mapped(), romanized, pronunciations })
+}
+
+// 자막 파일(SRT, WebVTT)을 변환하고, 변환해서 너비를 넘게 된 줄을 경고로 돌려준다.
+async fn subtitle_handler(Query(query): Query<SubtitleQuery>, body: String, dic: Arc<rust_web::Dictionary>) -> Response {
+    let defaults = ConvertOptions::default();
+    let options = ConvertOptions {
+        mode: query.mode.unwrap_or(defaults.mode),
+        dueum: query.dueum.unwrap_or(defaults.dueum),
+        normalize: query.normalize.unwrap_or(defaults.normalize),
+        traditional: query.traditional.unwrap_or(defaults.traditional),
+    };
+    let format = query.format.unwrap_or_else(|| SubtitleFormat::detect(&body));
+    match dic.convert_subtitle(&body, format, &options, query.max_width.unwrap_or(DEFAULT_MAX_WIDTH)) {
+        Ok(result) => Json(result).into_response(),
+        Err(msg) => (StatusCode::BAD_REQUEST, msg).into_response(),
+    }
+}
+
+// 표 파일(CSV, TSV)의 지정한 열을 변환해서 같은 형식의 표로 돌려준다.
+async fn table_handler(Query(query): Query<TableQuery>, body: String, dic: Arc<rust_web::Dictionary>) -> Response {
+    let defaults = ConvertOptions::default();
+    let options = ConvertOptions {
+        mode: query.mode.unwrap_or(defaults.mode),
+        dueum: query.dueum.unwrap_or(defaults.dueum),
+        normalize: query.normalize.unwrap_or(defaults.normalize),
+        traditional: query.traditional.unwrap_or(defaults.traditional),
+    };
+    let table_defaults = TableOptions::default();
+    let table = TableOptions {
+        columns: query.columns.split(',').map(|column| column.trim().to_string()).filter(|column| !column.is_empty()).collect(),
+        header: query.header.unwrap_or(table_defaults.header),
+        append: query.append.unwrap_or(table_defaults.append),
+        report: query.report.unwrap_or(table_defaults.report),
+    };
+    let format = query.format.unwrap_or(TableFormat::Csv);
+    match dic.convert_table(&body, format, &table, &options) {
+        Ok(result) => ([(header::CONTENT_TYPE, format.content_type())], result.table).into_response(),
+        Err(msg) => (StatusCode::BAD_REQUEST, msg).into_response(),
+    }
+}
+
+// JSON 문서에서 선택한 문자열만 변환해서 같은 구조로 돌려주고, 선택자마다 변환한 문자열의 수를 알려 준다.
+async fn json_handler(ExtractJson(payload): ExtractJson<JsonInput>, dic: Arc<rust_web::Dictionary>) -> Response {
+    match dic.convert_json(payload.document, &payload.paths, &payload.options) {
+        Ok(result) => Json(result).into_response(),
+        Err(msg) => (StatusCode::BAD_REQUEST, msg).into_response(),
+    }
+}
+
+// EPUB, DOCX 파일 올리기 (multipart/form-data)
+// file 필드에 문서 파일, mode, dueum, normalize, traditional 필드에 옵션을 넣는다. 변환한 문서 파일을 돌려준다.
+async fn document_handler(dic: Arc<rust_web::Dictionary>, mut multipart: Multipart) -> Response {
+    let mut file: Option<(String, Vec<u8>)> = None;
+    let mut options = ConvertOptions::default();
+    loop {
+        let field = match multipart.next_field().await {
+            Ok(Some(field)) => field,
+            Ok(None) => break,
+            Err(e) => return (e.status(), e.body_text()).into_response(),
+        };
+        let name = field.name().unwrap_or("").to_string();
+        if name == "file" {
+            let file_name = field.file_name().unwrap_or("").to_string();
+            match field.bytes().await {
+                Ok(bytes) => file = Some((file_name, bytes.to_vec())),
+                Err(e) => return (e.status(), e.body_text()).into_response(),
+            }
+            continue;
+        }
+
+        let value = match field.text().await {
+            Ok(value) => value,
+            Err(e) => return (e.status(), e.body_text()).into_response(),
+        };
+        let parsed = match name.as_str() {
+            "mode" => OutputMode::parse(&value).map(|mode| options.mode = mode),
+            "normalize" => Normalize::parse(&value).map(|normalize| options.normalize = normalize),
+            "dueum" => value.parse().ok().map(|dueum| options.dueum = dueum),
+            "traditional" => value.parse().ok().map(|traditional| options.traditional = traditional),
+            _ => Some(()),
+        };
+        if parsed.is_none() {
+            return (StatusCode::BAD_REQUEST, format!("잘못된 {} 값: {}", name, value)).into_response();
+        }
+    }
+
+    let Some((file_name, bytes)) = file else {
+        return (StatusCode::BAD_REQUEST, "file 필드에 문서 파일을 넣어 주세요.").into_response();
+    };
+    let Some(kind) = DocumentKind::from_file_name(&file_name) else {
+        return (StatusCode::BAD_REQUEST, "EPUB, DOCX 파일만 변환할 수 있습니다.").into_response();
+    };
+
+    // 책 한 권을 변환하는 데는 시간이 걸리므로 다른 요청을 막지 않도록 별도의 스레드에서 변환한다.
+    let converted = tokio::task::spawn_blocking(move || dic.convert_document(&bytes, kind, &options)).await;
+    match converted {
+        Ok(Ok(result)) => (
+            [
+                (header::CONTENT_TYPE, kind.content_type().to_string()),
+                (header::CONTENT_DISPOSITION, content_disposition(&file_name)),
+            ],
+            result.bytes,
+        ).into_response(),
+        Ok(Err(msg)) => (StatusCode::BAD_REQUEST, msg).into_response(),
+        Err(e) => {
+            log::error!("문서 변환 실패: {}", e);
+            (StatusCode::INTERNAL_SERVER_ERROR, "서버 내부 오류가 발생했습니다.").into_response()
         }
-    }        
+    }
+}
+
+// 내려받을 파일 이름. 한글 파일 이름은 filename*에 UTF-8로 넣고, filename에는 ASCII가 아닌 글자를 '_'로 바꾸어 넣는다.
+fn content_disposition(file_name: &str) -> String {
+    let file_name = file_name.rsplit(['/', '\\']).next().unwrap_or(file_name);
+    let ascii = file_name.chars()
+        .map(|c| if c.is_ascii_graphic() && c != '"' && c != '\\' || c == ' ' { c } else { '_' })
+        .collect::<String>();
+    let encoded = file_name.bytes()
+        .map(|b| if b.is_ascii_alphanumeric() || b"-._~".contains(&b) { (b as char).to_string() } else { format!("%{:02X}", b) })
+        .collect::<String>();
+    format!("attachment; filename=\"{}\"; filename*=UTF-8''{}", ascii, encoded)
+}
+
+// 문서에 나온 한자 단어와 한자의 읽기, 횟수, 급수
+async fn analyze_handler(ExtractJson(payload): ExtractJson<AnalyzeInput>, dic: Arc<rust_web::Dictionary>) -> Response {
+    let analysis = dic.analyze(&payload.text, &payload.options);
+    match payload.format {
+        ReportFormat::Json => Json(analysis).into_response(),
+        ReportFormat::Csv => (
+            [
+                (header::CONTENT_TYPE, "text/csv; charset=utf-8"),
+                (header::CONTENT_DISPOSITION, "attachment; filename=\"hanja_vocabulary.csv\""),
+            ],
+            // 엑셀에서 UTF-8로 열리도록 BOM을 붙인다.
+            format!("\u{FEFF}{}", analysis.to_csv()),
+        ).into_response(),
+    }
+}
+
+// 한자 한 글자의 읽기, 훈, 부수, 획수, 급수 등
+// 결과는 글자와 사전이 같으면 바뀌지 않으므로 사전 버전으로 ETag를 만든다.
+async fn char_info_handler(
+        headers: HeaderMap,
+        Path(c): Path<String>,
+        dic: Arc<rust_web::Dictionary>) -> Response {
+    let mut chars = c.chars();
+    let c = match (chars.next(), chars.next()) {
+        (Some(c), None) => c,
+        _ => return (StatusCode::BAD_REQUEST, "한자 한 글자를 지정해 주세요.").into_response(),
+    };
+    let Some(detail) = dic.char_detail(c) else {
+        return (StatusCode::BAD_REQUEST, "한자가 아닙니다.").into_response();
+    };
+
+    let etag = rust_web::weak_etag(&rust_web::digest_hex(&[c.to_string().as_bytes(), dic.version.as_bytes()]));
+    if etag_matches(&headers, &etag) {
+        return (StatusCode::NOT_MODIFIED, [(header::ETAG, etag)]).into_response();
+    }
+    (
+        [(header::ETAG, etag), (header::CACHE_CONTROL, "public, max-age=3600".to_string())],
+        Json(detail),
+    ).into_response()
+}
+
+// 읽기, 부수, 획수, 급수로 한자 찾기
+async fn search_handler(Query(query): Query<SearchQuery>, dic: Arc<rust_web::Dictionary>) -> Response {
+    match dic.search(&query) {
+        Ok(page) => Json(page).into_response(),
+        Err(msg) => (StatusCode::BAD_REQUEST, msg).into_response(),
+    }
+}
+
+// 변환 캐시의 적중/실패 횟수와 크기
+async fn cache_stats_handler(cache: Arc<ConversionCache>) -> Json<CacheStats> {
+    Json(cache.stats())
+}
+
+// If-None-Match 헤더에 etag가 있는지 확인한다. GET, HEAD 요청에서만 사용한다.
+fn etag_matches(headers: &HeaderMap, etag: &str) -> bool {
+    headers.get(header::IF_NONE_MATCH)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| rust_web::if_none_match(v, etag))
+}
+
+// Authorization 헤더에 API 키("Bearer <키>")가 있으면 키 별로, 없으면 IP 별로 요청 속도를 제한한다.
+// 알 수 없는 키는 IP 별 제한에 포함한 다음 401, 제한을 넘은 요청은 Retry-After 헤더와 함께 429를 돌려준다.
+async fn access_control(
+        State(access): State<Arc<AccessControl>>,
+        ConnectInfo(addr): ConnectInfo<SocketAddr>,
+        request: Request,
+        next: Next) -> Response {
+    let authorization = request.headers().get(header::AUTHORIZATION).map(|value| value.to_str().unwrap_or(""));
+    match access.check(authorization, &addr.ip().to_string()) {
+        Ok(()) => next.run(request).await,
+        Err(denied) => denied_response(denied),
+    }
+}
+
+// 운영용 API는 유효한 API 키가 있는 요청만 받는다.
+async fn require_api_key(
+        State(access): State<Arc<AccessControl>>,
+        ConnectInfo(addr): ConnectInfo<SocketAddr>,
+        request: Request,
+        next: Next) -> Response {
+    let authorization = request.headers().get(header::AUTHORIZATION).map(|value| value.to_str().unwrap_or(""));
+    match access.check_key(authorization, &addr.ip().to_string()) {
+        Ok(()) => next.run(request).await,
+        Err(denied) => denied_response(denied),
+    }
+}
+
+fn denied_response(denied: Denied) -> Response {
+    match denied {
+        Denied::InvalidKey => (
+            StatusCode::UNAUTHORIZED,
+            [(header::WWW_AUTHENTICATE, "Bearer")],
+            "유효하지 않은 API 키입니다.",
+        ).into_response(),
+        Denied::TooManyRequests(wait) => {
+            let retry_after = wait.as_secs_f64().ceil().max(1.0) as u64;
+            (
+                StatusCode::TOO_MANY_REQUESTS,
+                [(header::RETRY_AFTER, retry_after.to_string())],
+                "요청이 너무 많습니다. 잠시 후 다시 시도해 주세요.",
+            ).into_response()
+        },
+    }
+}
+
+fn content_security_policy(config: &rust_web::Config) -> HeaderValue {
+    let csp = format!("default-src 'self'; object-src 'none'; base-uri 'self'; frame-ancestors {}", config.frame_ancestors);
+    HeaderValue::from_str(&csp).unwrap_or_else(|e| {
+        log::error!("잘못된 frame_ancestors 설정 {}: {}", config.frame_ancestors, e);
+        HeaderValue::from_static("default-src 'self'; object-src 'none'; base-uri 'self'; frame-ancestors 'none'")
+    })
 }
 
 
 #[tokio::main]
 async fn main() {  
+    // 0. rust_web convert ... : 웹 서버 없이 명령행에서 변환한다.
+    //    rust_web lint-dic      : 사전을 검사한다.
+    let args: Vec<String> = std::env::args().collect();
+    match args.get(1).map(|s| s.as_str()) {
+        Some("convert") => std::process::exit(cli::run(&args[2..])),
+        Some("lint-dic") => std::process::exit(cli::lint_dic()),
+        _ => {}
+    }
+
     // 1. log4rs 설정
     log4rs::init_file("log4rs.yml", Default::default()).unwrap();
     log::info!("Starting server...");
@@ -110,19 +563,122 @@ async fn https_server() {
     let config = Arc::new(rust_web::get_config());
     println!("Running in mode: {}", config.mode);
 
-    //3. https 서버를 시작한다.
-    let app = Router::new()
+    //3. API 키와 요청 속도 제한 설정
+    let access = Arc::new(AccessControl {
+        api_keys: ApiKeys::load(config.api_keys_file),
+        key_limiter: RateLimiter::new(config.key_rate_per_min, config.key_burst),
+        anon_limiter: RateLimiter::new(config.anon_rate_per_min, config.anon_burst),
+    });
+    log::info!("API 키 {}개 로드", access.api_keys.len());
+
+    //4. https 서버를 시작한다.
+    let cache = Arc::new(ConversionCache::new(config.conversion_cache_bytes));
+
+    // HTML 페이지와 정적 파일에는 보안 헤더를 붙인다.
+    let assets = Arc::new(Assets::new(config.assets_from_disk));
+    let pages = Router::new()
     .route("/", get(hello_rust))
+    .route("/css/{*file}", get(static_file))
+    .route("/js/{*file}", get(static_file))
+    .with_state(assets)
+    .layer(SetResponseHeaderLayer::overriding(
+        header::CONTENT_SECURITY_POLICY,
+        content_security_policy(&config),
+    ))
+    .layer(SetResponseHeaderLayer::overriding(
+        header::X_CONTENT_TYPE_OPTIONS,
+        HeaderValue::from_static("nosniff"),
+    ))
+    .layer(SetResponseHeaderLayer::overriding(
+        header::REFERRER_POLICY,
+        HeaderValue::from_static("strict-origin-when-cross-origin"),
+    ));
+
+    let app = Router::new()
     .route(
         "/convert", 
         post({                
             let  dic_clone = std::sync::Arc::clone(&dic_arc);
-            move |payload| convert_handler(payload, dic_clone)                
+            let cache_clone = Arc::clone(&cache);
+            move |payload| convert_handler(payload, dic_clone, cache_clone)                
+        })
+        .get({
+            let dic_clone = Arc::clone(&dic_arc);
+            let cache_clone = Arc::clone(&cache);
+            move |headers, query| convert_get_handler(headers, query, dic_clone, cache_clone)
+        })
+        .layer(DefaultBodyLimit::max(config.convert_body_limit))
+        .layer(middleware::from_fn_with_state(Arc::clone(&access), access_control)),
+    )
+    .route(
+        "/subtitle",
+        post({
+            let dic_clone = Arc::clone(&dic_arc);
+            move |query, body| subtitle_handler(query, body, dic_clone)
+        })
+        .layer(DefaultBodyLimit::max(config.convert_body_limit))
+        .layer(middleware::from_fn_with_state(Arc::clone(&access), access_control)),
+    )
+    .route(
+        "/table",
+        post({
+            let dic_clone = Arc::clone(&dic_arc);
+            move |query, body| table_handler(query, body, dic_clone)
+        })
+        .layer(DefaultBodyLimit::max(config.convert_body_limit))
+        .layer(middleware::from_fn_with_state(Arc::clone(&access), access_control)),
+    )
+    .route(
+        "/json",
+        post({
+            let dic_clone = Arc::clone(&dic_arc);
+            move |payload| json_handler(payload, dic_clone)
+        })
+        .layer(DefaultBodyLimit::max(config.convert_body_limit))
+        .layer(middleware::from_fn_with_state(Arc::clone(&access), access_control)),
+    )
+    .route(
+        "/document",
+        post({
+            let dic_clone = Arc::clone(&dic_arc);
+            move |multipart| document_handler(dic_clone, multipart)
+        })
+        .layer(DefaultBodyLimit::max(config.document_body_limit))
+        .layer(middleware::from_fn_with_state(Arc::clone(&access), access_control)),
+    )
+    .route(
+        "/analyze",
+        post({
+            let dic_clone = Arc::clone(&dic_arc);
+            move |payload| analyze_handler(payload, dic_clone)
+        })
+        .layer(DefaultBodyLimit::max(config.convert_body_limit))
+        .layer(middleware::from_fn_with_state(Arc::clone(&access), access_control)),
+    )
+    .route(
+        "/api/char/{c}",
+        get({
+            let dic_clone = Arc::clone(&dic_arc);
+            move |headers, path| char_info_handler(headers, path, dic_clone)
         }),
     )
-    .nest_service("/css", ServeDir::new("css"))
-    .nest_service("/js", ServeDir::new("js"))
-    ;
+    .route(
+        "/api/search",
+        get({
+            let dic_clone = Arc::clone(&dic_arc);
+            move |query| search_handler(query, dic_clone)
+        }),
+    )
+    .route(
+        "/api/cache",
+        get({
+            let cache_clone = Arc::clone(&cache);
+            move || cache_stats_handler(cache_clone)
+        })
+        .layer(middleware::from_fn_with_state(Arc::clone(&access), require_api_key)),
+    )
+    .merge(pages);
+    let app = with_common_layers(app, &config);
 
     // for https    
     let rustls_config = match RustlsConfig::from_pem_file(
@@ -139,7 +695,7 @@ async fn https_server() {
     let https_addr = config.base_https_url.parse::<SocketAddr>().unwrap(); //127.0.0.1:443 or 0.0.0.1:443
     println!("HTTPS Listening on {}", https_addr);
     axum_server::bind_rustls(https_addr, rustls_config)
-        .serve(app.into_make_service())
+        .serve(app.into_make_service_with_connect_info::<SocketAddr>())
         .await
         .unwrap();
 }
@@ -0,0 +1,80 @@
+// Markdown 문서에서 본문 글자의 한자만 변환한다.
+// pulldown-cmark로 문서 구조를 읽고, 본문 텍스트(Text 이벤트)가 있는 위치만 변환한다.
+// 코드 블록, 인라인 코드, 링크 주소, HTML, 앞머리(YAML/TOML front matter)는 변환하지 않고,
+// 본문 안의 인라인 HTML(<code>, <kbd>, translate="no" 등)도 HTML 형식과 같은 규칙으로 안쪽을 건너뛴다.
+// 변환한 한자 외의 부분은 입력과 byte 단위로 같다.
+
+use std::ops::Range;
+
+use pulldown_cmark::{Event, LinkType, Options, Parser, Tag};
+
+use crate::conversion;
+use crate::html;
+use crate::{Conversion, ConvertOptions, Dictionary, Normalize, OutputMode, Span};
+
+impl Dictionary {
+    pub fn convert_markdown(&self, input: &str, options: &ConvertOptions) -> Conversion {
+        // 1. 변환할 본문 텍스트의 위치를 모은다.
+        //    열린 요소마다 안쪽을 건너뛸지 기억해 두고, 하나라도 건너뛰면 변환하지 않는다.
+        let parser_options = Options::ENABLE_TABLES
+            | Options::ENABLE_FOOTNOTES
+            | Options::ENABLE_STRIKETHROUGH
+            | Options::ENABLE_TASKLISTS
+            | Options::ENABLE_YAML_STYLE_METADATA_BLOCKS
+            | Options::ENABLE_PLUSES_DELIMITED_METADATA_BLOCKS;
+        let mut ranges: Vec<Range<usize>> = Vec::new();
+        let mut skips: Vec<bool> = Vec::new();
+        // 본문 안에서 열린 인라인 HTML 요소 (이름, 안쪽을 건너뛸지, 열린 곳의 skips 깊이)
+        // 닫는 태그 없이 문단 등이 끝나면 함께 닫는다.
+        let mut inline: Vec<(String, bool, usize)> = Vec::new();
+        for (event, range) in Parser::new_ext(input, parser_options).into_offset_iter() {
+            match event {
+                Event::Start(tag) => skips.push(skips_inside(&tag, options.mode)),
+                Event::End(_) => {
+                    skips.pop();
+                    inline.retain(|(_, _, depth)| *depth <= skips.len());
+                },
+                Event::InlineHtml(markup) => match html::inline_tag(&markup, options.mode) {
+                    Some((name, false, skip)) => inline.push((name, skip, skips.len())),
+                    Some((name, true, _)) => {
+                        if let Some(i) = inline.iter().rposition(|(open, _, _)| *open == name) {
+                            inline.truncate(i);
+                        }
+                    },
+                    None => {},
+                },
+                Event::Text(_) if !skips.contains(&true) && !inline.iter().any(|(_, skip, _)| *skip) => match ranges.last_mut() {
+                    // 이스케이프(\*) 등으로 나뉜 텍스트는 이어 붙여서 단어를 찾는다.
+                    Some(last) if last.end == range.start => last.end = range.end,
+                    _ => ranges.push(range),
+                },
+                _ => {},
+            }
+        }
+
+        // 2. 텍스트는 변환하고, 그 사이는 그대로 둔다.
+        let mut spans: Vec<Span> = Vec::new();
+        let mut pos = 0;
+        for range in ranges {
+            if range.start < pos || range.end > input.len() {
+                continue;
+            }
+            conversion::push_plain(&mut spans, input, pos, range.start);
+            self.convert_range(&mut spans, input, range.start, range.end, true, options);
+            pos = range.end;
+        }
+        conversion::push_plain(&mut spans, input, pos, input.len());
+        Conversion { spans, rewrite: options.normalize == Normalize::Rewrite }
+    }
+}
+
+// 안쪽의 텍스트를 변환하지 않는 요소
+// 자동 링크(<https://...>)는 텍스트가 주소이다. 이미지의 대체 텍스트에는 <ruby>를 넣을 수 없다.
+fn skips_inside(tag: &Tag, mode: OutputMode) -> bool {
+    match tag {
+        Tag::CodeBlock(_) | Tag::HtmlBlock | Tag::MetadataBlock(_) => true,
+        Tag::Link { link_type: LinkType::Autolink | LinkType::Email, .. } => true,
+        Tag::Image { .. } => mode == OutputMode::Ruby,
+        _ => false,
+    }
+}
@@ -0,0 +1,280 @@
+// 한글 읽기의 표준 발음을 만든다. (표준 발음법)
+//   연음(제13, 14항), 'ㅎ' 탈락과 거센소리되기(제12항), 구개음화(제17항),
+//   비음화(제18항: 국민 -> 궁민), 'ㄹ'의 비음화(제19항: 독립 -> 동닙, 종로 -> 종노), 유음화(제20항: 신라 -> 실라),
+//   된소리되기: 받침 'ㄱ, ㄷ, ㅂ' 뒤(제23항: 학교 -> 학꾜), 한자어에서 'ㄹ' 받침 뒤의 'ㄷ, ㅅ, ㅈ'(제26항: 발전 -> 발쩐)
+// 소리의 변화는 단어 안에서만 반영한다. 단어는 이어진 한글이고, 이어진 한자 중 불규칙 변환 한자사전에서 찾은 단어는 앞뒤의 한자와 나눈다.
+// 로마자 표기(romanize.rs)도 같은 규칙으로 소리의 변화를 반영하지만, 된소리되기와 받침 'ㄱ, ㄷ, ㅂ, ㅈ' 뒤의 거센소리되기는 적지 않는다.
+
+use serde::Serialize;
+
+use crate::{Conversion, OutputMode, Span, SpanKind};
+
+const INITIALS: [char; 19] = ['ㄱ', 'ㄲ', 'ㄴ', 'ㄷ', 'ㄸ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅃ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅉ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ'];
+// 받침. 겹받침은 두 글자로 나누어 둔다.
+const FINALS: [&str; 28] = [
+    "", "ㄱ", "ㄲ", "ㄱㅅ", "ㄴ", "ㄴㅈ", "ㄴㅎ", "ㄷ", "ㄹ", "ㄹㄱ", "ㄹㅁ", "ㄹㅂ", "ㄹㅅ", "ㄹㅌ",
+    "ㄹㅍ", "ㄹㅎ", "ㅁ", "ㅂ", "ㅂㅅ", "ㅅ", "ㅆ", "ㅇ", "ㅈ", "ㅊ", "ㅋ", "ㅌ", "ㅍ", "ㅎ",
+];
+const VOWEL_I: usize = 20;  // ㅣ
+
+// 한자 단어 하나의 표준 발음
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Pronunciation {
+    pub start: usize,  // 입력 문자열에서의 시작 byte 위치
+    pub end: usize,    // 입력 문자열에서의 끝 byte 위치
+    pub source: String,
+    pub reading: String,        // 변환한 한글 (철자)
+    pub pronunciation: String,  // 표준 발음 (대괄호 없이)
+}
+
+// 반영할 소리의 변화
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Rules {
+    pub tensification: bool,  // 된소리되기
+    pub aspiration: bool,     // 받침 'ㄱ, ㄷ, ㅂ, ㅈ' 뒤의 'ㅎ'과 합친 거센소리되기 (백화점 -> 배콰점)
+}
+
+pub(crate) const STANDARD: Rules = Rules { tensification: true, aspiration: true };
+
+// 음절 하나. 소리의 변화를 반영하면서 초성과 받침이 바뀐다.
+pub(crate) struct Syllable {
+    pub initial: char,
+    pub vowel: usize,
+    pub coda: Vec<char>,
+    hanja: bool,  // 한자의 읽기인지 (제26항)
+}
+
+impl Syllable {
+    pub(crate) fn decompose(c: char, hanja: bool) -> Option<Syllable> {
+        let n = (c as u32).checked_sub(0xAC00).filter(|n| *n < 11172)? as usize;
+        Some(Syllable { initial: INITIALS[n / 588], vowel: n % 588 / 28, coda: FINALS[n % 28].chars().collect(), hanja })
+    }
+
+    // 소리의 변화를 반영한 다음의 받침은 한 글자이다.
+    fn compose(&self) -> char {
+        let initial = INITIALS.iter().position(|c| *c == self.initial).unwrap_or(11);
+        let coda = self.coda.iter().collect::<String>();
+        let coda = FINALS.iter().position(|c| *c == coda).unwrap_or(0);
+        char::from_u32(0xAC00 + (initial * 588 + self.vowel * 28 + coda) as u32).unwrap_or('\u{FFFD}')
+    }
+}
+
+// 한글 문자열의 표준 발음. 한글이 아닌 글자는 그대로 두고, 한글이 아닌 글자에서 단어를 나눈다.
+pub fn pronounce(text: &str) -> String {
+    let mut out = String::new();
+    let mut word: Vec<Syllable> = Vec::new();
+    for c in text.chars() {
+        match Syllable::decompose(c, false) {
+            Some(syllable) => word.push(syllable),
+            None => {
+                write_word(&mut word, &mut out);
+                out.push(c);
+            },
+        }
+    }
+    write_word(&mut word, &mut out);
+    out
+}
+
+fn write_word(word: &mut Vec<Syllable>, out: &mut String) {
+    apply(word, STANDARD);
+    out.extend(word.iter().map(|syllable| syllable.compose()));
+    word.clear();
+}
+
+impl Conversion {
+    // 한자 단어마다 표준 발음. 사전에 없는 한자는 단어에 넣지 않는다.
+    pub fn pronunciations(&self) -> Vec<Pronunciation> {
+        let mut pronunciations: Vec<Pronunciation> = Vec::new();
+        let mut word: Vec<Syllable> = Vec::new();
+        let mut prev: Option<&Span> = None;
+        for span in &self.spans {
+            let in_word = matches!(span.kind, SpanKind::Word | SpanKind::Rule | SpanKind::Char);
+            let continues = in_word && prev.is_some_and(|prev| matches!(prev.kind, SpanKind::Word | SpanKind::Rule | SpanKind::Char) && !is_word_boundary(prev, span));
+            if !continues {
+                finish_word(&mut pronunciations, &mut word);
+            }
+            if in_word {
+                match pronunciations.last_mut() {
+                    Some(last) if continues => {
+                        last.end = span.end;
+                        last.source.push_str(&span.source);
+                        last.reading.push_str(&span.reading);
+                    },
+                    _ => pronunciations.push(Pronunciation {
+                        start: span.start,
+                        end: span.end,
+                        source: span.source.clone(),
+                        reading: span.reading.clone(),
+                        pronunciation: String::new(),
+                    }),
+                }
+                word.extend(span.reading.chars().filter_map(|c| Syllable::decompose(c, true)));
+            }
+            prev = Some(span);
+        }
+        finish_word(&mut pronunciations, &mut word);
+        pronunciations
+    }
+
+    // render의 결과에서 발음이 철자와 다른 한자 단어마다 뒤에 [발음]을 붙인다.
+    // 괄호 형식에서 여러 단어를 하나로 묶어 출력하면 묶음 뒤에 단어 순서대로 붙인다.
+    pub fn render_pronounced(&self, mode: OutputMode) -> String {
+        let pronunciations = self.pronunciations();
+        let mut next = pronunciations.iter().filter(|p| p.pronunciation != p.reading).peekable();
+        let mut out = String::new();
+        for piece in self.render_pieces(mode) {
+            out.push_str(&piece.text);
+            while let Some(p) = next.next_if(|p| p.end <= piece.end) {
+                out.push_str(&format!("[{}]", p.pronunciation));
+            }
+        }
+        out
+    }
+}
+
+// 모은 음절에 소리의 변화를 반영해서 마지막 단어의 발음으로 넣는다.
+fn finish_word(pronunciations: &mut [Pronunciation], word: &mut Vec<Syllable>) {
+    if word.is_empty() {
+        return;
+    }
+    apply(word, STANDARD);
+    if let Some(last) = pronunciations.last_mut() {
+        last.pronunciation = word.iter().map(|syllable| syllable.compose()).collect();
+    }
+    word.clear();
+}
+
+// 이어진 두 구간 사이가 소리의 변화를 반영하지 않는 단어 경계인지.
+// 불규칙 변환 한자사전이나 복합어 규칙으로 찾은 단어와 그 앞뒤의 한자 사이. 한자와 이어진 한글(조사 등)과는 한 단어로 본다.
+pub(crate) fn is_word_boundary(prev: &Span, span: &Span) -> bool {
+    let is_word = |span: &Span| matches!(span.kind, SpanKind::Word | SpanKind::Rule);
+    prev.is_hanja() && span.is_hanja() && (is_word(prev) || is_word(span))
+}
+
+// 단어 하나에 소리의 변화를 반영한다. 단어 끝의 받침은 대표음으로 바꾼다.
+pub(crate) fn apply(word: &mut [Syllable], rules: Rules) {
+    if word.is_empty() {
+        return;
+    }
+    for i in 1..word.len() {
+        let (left, right) = word.split_at_mut(i);
+        link(&mut left[i - 1], &mut right[0], rules);
+    }
+    let last = word.len() - 1;
+    word[last].coda = representative(&word[last].coda).into_iter().collect();
+}
+
+// 앞 음절의 받침과 뒤 음절의 초성 사이의 소리의 변화
+fn link(cur: &mut Syllable, next: &mut Syllable, rules: Rules) {
+    if cur.coda.is_empty() {
+        return;
+    }
+
+    // 1. 뒤 음절이 모음으로 시작하면 받침을 뒤로 넘긴다. 'ㅎ'은 소리 나지 않고, 'ㄷ', 'ㅌ'은 'ㅣ' 앞에서 'ㅈ', 'ㅊ'이 된다.
+    //    겹받침의 'ㅅ'은 된소리로 넘긴다. (값이 -> 갑씨)
+    if next.initial == 'ㅇ' {
+        if cur.coda.last() == Some(&'ㅎ') {
+            cur.coda.pop();
+        }
+        if let Some(&c) = cur.coda.last().filter(|c| **c != 'ㅇ') {
+            cur.coda.pop();
+            next.initial = match c {
+                'ㄷ' if next.vowel == VOWEL_I => 'ㅈ',
+                'ㅌ' if next.vowel == VOWEL_I => 'ㅊ',
+                'ㅅ' if rules.tensification && !cur.coda.is_empty() => 'ㅆ',
+                _ => c,
+            };
+        }
+        cur.coda = representative(&cur.coda).into_iter().collect();
+        return;
+    }
+
+    // 2. 받침 'ㅎ'은 뒤의 'ㄱ', 'ㄷ', 'ㅈ'과 합쳐 거센소리가 되고, 'ㄴ' 앞에서는 'ㄴ'이 된다.
+    if cur.coda.contains(&'ㅎ') {
+        cur.coda.retain(|c| *c != 'ㅎ');
+        match next.initial {
+            'ㄱ' => next.initial = 'ㅋ',
+            'ㄷ' => next.initial = 'ㅌ',
+            'ㅈ' => next.initial = 'ㅊ',
+            'ㅅ' if rules.tensification => next.initial = 'ㅆ',
+            'ㄴ' if cur.coda.is_empty() => cur.coda.push('ㄴ'),
+            _ => {},
+        }
+    }
+
+    // 3. 받침 'ㄱ, ㄷ, ㅂ, ㅈ'은 뒤의 'ㅎ'과 합쳐 거센소리가 된다.
+    if rules.aspiration && next.initial == 'ㅎ' {
+        let aspirated = match cur.coda.last() {
+            Some('ㄱ' | 'ㄲ') => Some('ㅋ'),
+            Some('ㄷ' | 'ㅅ') => Some('ㅌ'),
+            Some('ㅂ') => Some('ㅍ'),
+            Some('ㅈ') => Some('ㅊ'),
+            _ => None,
+        };
+        if let Some(aspirated) = aspirated {
+            cur.coda.pop();
+            next.initial = aspirated;
+            cur.coda = representative(&cur.coda).into_iter().collect();
+            return;
+        }
+    }
+
+    // 4. 받침을 대표음으로 바꾸고 비음화, 유음화를 반영한다.
+    let Some(mut coda) = representative(&cur.coda) else { return };
+    match (coda, next.initial) {
+        ('ㄱ' | 'ㄷ' | 'ㅂ', 'ㄴ' | 'ㅁ') => coda = nasal(coda),
+        ('ㄱ' | 'ㄷ' | 'ㅂ', 'ㄹ') => {
+            coda = nasal(coda);
+            next.initial = 'ㄴ';
+        },
+        ('ㅁ' | 'ㅇ', 'ㄹ') => next.initial = 'ㄴ',
+        ('ㄴ', 'ㄹ') => coda = 'ㄹ',
+        ('ㄹ', 'ㄴ') => next.initial = 'ㄹ',
+        _ => {},
+    }
+
+    // 5. 된소리되기
+    if rules.tensification {
+        let tense = match (coda, next.initial) {
+            ('ㄱ' | 'ㄷ' | 'ㅂ', _) => true,
+            ('ㄹ', 'ㄷ' | 'ㅅ' | 'ㅈ') => cur.hanja && next.hanja,
+            _ => false,
+        };
+        if tense {
+            next.initial = match next.initial {
+                'ㄱ' => 'ㄲ',
+                'ㄷ' => 'ㄸ',
+                'ㅂ' => 'ㅃ',
+                'ㅅ' => 'ㅆ',
+                'ㅈ' => 'ㅉ',
+                c => c,
+            };
+        }
+    }
+    cur.coda = vec![coda];
+}
+
+// 받침의 대표음: ㄱ ㄴ ㄷ ㄹ ㅁ ㅂ ㅇ. 겹받침 ㄺ, ㄻ, ㄿ은 뒤의 자음, 나머지는 앞의 자음으로 소리 난다.
+fn representative(coda: &[char]) -> Option<char> {
+    let c = match coda {
+        [] => return None,
+        ['ㄹ', second @ ('ㄱ' | 'ㅁ' | 'ㅍ')] => *second,
+        [first, ..] => *first,
+    };
+    Some(match c {
+        'ㄱ' | 'ㄲ' | 'ㅋ' => 'ㄱ',
+        'ㄷ' | 'ㅅ' | 'ㅆ' | 'ㅈ' | 'ㅊ' | 'ㅌ' | 'ㅎ' => 'ㄷ',
+        'ㅂ' | 'ㅍ' => 'ㅂ',
+        _ => c,
+    })
+}
+
+fn nasal(c: char) -> char {
+    match c {
+        'ㄱ' => 'ㅇ',
+        'ㄷ' => 'ㄴ',
+        'ㅂ' => 'ㅁ',
+        _ => c,
+    }
+}
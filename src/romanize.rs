@@ -0,0 +1,171 @@
+// 한글을 로마자로 적는다.
+//   - rr: 국어의 로마자 표기법 (Revised Romanization of Korean)
+//   - mr: 매큔-라이샤워 표기법 (McCune–Reischauer)
+// 이어진 한글 음절을 한 단어로 보고, 단어 안에서 표준 발음과 같이 소리의 변화를 반영한다. (pronunciation.rs 참조)
+// 된소리되기와 받침 'ㄱ, ㄷ, ㅂ, ㅈ' 뒤의 거센소리되기(백화점 -> baekhwajeom)는 두 표기법 모두 적지 않는다.
+
+use serde::{Deserialize, Serialize};
+
+use crate::pronunciation::{self, Rules, Syllable};
+use crate::{Conversion, Span};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Romanization {
+    Rr,
+    Mr,
+}
+
+impl Romanization {
+    pub fn parse(s: &str) -> Option<Romanization> {
+        match s {
+            "rr" => Some(Romanization::Rr),
+            "mr" => Some(Romanization::Mr),
+            _ => None,
+        }
+    }
+}
+
+// ㅏ ㅐ ㅑ ㅒ ㅓ ㅔ ㅕ ㅖ ㅗ ㅘ ㅙ ㅚ ㅛ ㅜ ㅝ ㅞ ㅟ ㅠ ㅡ ㅢ ㅣ
+const VOWELS_RR: [&str; 21] = ["a", "ae", "ya", "yae", "eo", "e", "yeo", "ye", "o", "wa", "wae", "oe", "yo", "u", "wo", "we", "wi", "yu", "eu", "ui", "i"];
+const VOWELS_MR: [&str; 21] = ["a", "ae", "ya", "yae", "ŏ", "e", "yŏ", "ye", "o", "wa", "wae", "oe", "yo", "u", "wŏ", "we", "wi", "yu", "ŭ", "ŭi", "i"];
+// 로마자 표기에 반영하는 소리의 변화
+const RULES: Rules = Rules { tensification: false, aspiration: false };
+
+// 한글이 아닌 글자는 그대로 둔다. breaks는 소리의 변화를 반영하지 않는 단어 경계(text의 byte 위치, 오름차순)
+pub fn romanize(text: &str, breaks: &[usize], system: Romanization) -> String {
+    let mut out = String::new();
+    let mut word: Vec<Syllable> = Vec::new();
+    let mut breaks = breaks.iter().peekable();
+    for (i, c) in text.char_indices() {
+        while breaks.next_if(|&&b| b < i).is_some() {}
+        if breaks.next_if(|&&b| b == i).is_some() {
+            write_word(&mut word, system, &mut out);
+        }
+        match Syllable::decompose(c, false) {
+            Some(syllable) => word.push(syllable),
+            None => {
+                write_word(&mut word, system, &mut out);
+                out.push(c);
+            },
+        }
+    }
+    write_word(&mut word, system, &mut out);
+    out
+}
+
+impl Conversion {
+    // 한글 읽기를 로마자로 적는다. 출력 형식과 상관없이 한글로 변환한 결과를 적는다.
+    pub fn romanize(&self, system: Romanization) -> String {
+        let mut text = String::new();
+        let mut breaks = Vec::new();
+        let mut prev: Option<&Span> = None;
+        for span in &self.spans {
+            if prev.is_some_and(|prev| pronunciation::is_word_boundary(prev, span)) {
+                breaks.push(text.len());
+            }
+            text.push_str(if span.is_hanja() { &span.reading } else { &span.source });
+            prev = Some(span);
+        }
+        romanize(&text, &breaks, system)
+    }
+}
+
+// 단어 하나에 소리의 변화를 반영해서 로마자로 적고 비운다.
+fn write_word(word: &mut Vec<Syllable>, system: Romanization, out: &mut String) {
+    if word.is_empty() {
+        return;
+    }
+    pronunciation::apply(word, RULES);
+
+    for i in 0..word.len() {
+        let prev_coda = if i == 0 { None } else { Some(word[i - 1].coda.first().copied()) };
+        let syllable = &word[i];
+        match system {
+            Romanization::Rr => {
+                out.push_str(initial_rr(syllable.initial, prev_coda));
+                out.push_str(VOWELS_RR[syllable.vowel]);
+            },
+            Romanization::Mr => {
+                out.push_str(initial_mr(syllable.initial, prev_coda));
+                out.push_str(VOWELS_MR[syllable.vowel]);
+            },
+        }
+        if let Some(&coda) = syllable.coda.first() {
+            out.push_str(final_sound(coda));
+        }
+    }
+    word.clear();
+}
+
+fn final_sound(c: char) -> &'static str {
+    match c {
+        'ㄱ' => "k",
+        'ㄴ' => "n",
+        'ㄷ' => "t",
+        'ㄹ' => "l",
+        'ㅁ' => "m",
+        'ㅂ' => "p",
+        'ㅇ' => "ng",
+        _ => "",
+    }
+}
+
+// prev_coda는 단어의 첫 음절이면 None, 앞 음절에 받침이 없으면 Some(None)
+fn initial_rr(c: char, prev_coda: Option<Option<char>>) -> &'static str {
+    match c {
+        'ㄱ' => "g",
+        'ㄲ' => "kk",
+        'ㄴ' => "n",
+        'ㄷ' => "d",
+        'ㄸ' => "tt",
+        'ㄹ' if prev_coda == Some(Some('ㄹ')) => "l",
+        'ㄹ' => "r",
+        'ㅁ' => "m",
+        'ㅂ' => "b",
+        'ㅃ' => "pp",
+        'ㅅ' => "s",
+        'ㅆ' => "ss",
+        'ㅈ' => "j",
+        'ㅉ' => "jj",
+        'ㅊ' => "ch",
+        'ㅋ' => "k",
+        'ㅌ' => "t",
+        'ㅍ' => "p",
+        'ㅎ' => "h",
+        _ => "",
+    }
+}
+
+// 예사소리(ㄱ, ㄷ, ㅂ, ㅈ)는 모음이나 ㄴ, ㄹ, ㅁ, ㅇ 받침 뒤에서 유성음(g, d, b, j)으로 적는다.
+// 'ㄴ' 받침 뒤의 'ㄱ'은 'ㅇ' 받침과 구별하도록 n'g로 적는다.
+fn initial_mr(c: char, prev_coda: Option<Option<char>>) -> &'static str {
+    let voiced = matches!(prev_coda, Some(None | Some('ㄴ' | 'ㄹ' | 'ㅁ' | 'ㅇ')));
+    match c {
+        'ㄱ' if prev_coda == Some(Some('ㄴ')) => "'g",
+        'ㄱ' if voiced => "g",
+        'ㄱ' => "k",
+        'ㄲ' => "kk",
+        'ㄴ' => "n",
+        'ㄷ' if voiced => "d",
+        'ㄷ' => "t",
+        'ㄸ' => "tt",
+        'ㄹ' if prev_coda == Some(Some('ㄹ')) => "l",
+        'ㄹ' => "r",
+        'ㅁ' => "m",
+        'ㅂ' if voiced => "b",
+        'ㅂ' => "p",
+        'ㅃ' => "pp",
+        'ㅅ' => "s",
+        'ㅆ' => "ss",
+        'ㅈ' if voiced => "j",
+        'ㅈ' => "ch",
+        'ㅉ' => "tch",
+        'ㅊ' => "ch'",
+        'ㅋ' => "k'",
+        'ㅌ' => "t'",
+        'ㅍ' => "p'",
+        'ㅎ' => "h",
+        _ => "",
+    }
+}
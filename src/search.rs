@@ -0,0 +1,191 @@
+// 읽기, 부수, 획수, 급수로 한자를 찾는다.
+// 사전은 한자로만 찾을 수 있으므로, 사전을 로드할 때 읽기/부수/획수/급수 -> 한자 목록의 보조 색인을 만들어 둔다.
+// 호환용 한자(樂 락 등)는 따로 나오지 않고, 그 읽기가 통합 한자(樂)의 읽기로 합쳐진다.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::char_info::canonical_form;
+use crate::{Dictionary, EduLevel, HanjaInfo};
+
+const DEFAULT_PER_PAGE: usize = 50;
+const MAX_PER_PAGE: usize = 200;
+
+pub struct SearchIndex {
+    readings: HashMap<char, Vec<char>>,    // 한자 -> 읽기 (자주 쓰는 순서)
+    by_reading: HashMap<char, Vec<char>>,  // 읽기 -> 한자
+    by_radical: HashMap<char, Vec<char>>,  // 부수 -> 한자
+    by_strokes: HashMap<u8, Vec<char>>,    // 총획 -> 한자
+    by_grade: HashMap<&'static str, Vec<char>>,  // 급수 -> 한자
+}
+
+// 검색 조건. 지정한 조건을 모두 만족하는 한자를 찾는다.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct SearchQuery {
+    pub reading: Option<String>,     // 한글 한 음절
+    pub radical: Option<String>,     // 부수 한 글자
+    pub extra_strokes: Option<u8>,   // 부수를 뺀 나머지 획수
+    pub strokes: Option<u8>,         // 총획
+    pub grade: Option<String>,       // 한자능력검정시험 급수 (예: 8급, 7급II)
+    pub page: Option<usize>,         // 1부터 시작
+    pub per_page: Option<usize>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SearchPage {
+    pub total: usize,
+    pub page: usize,
+    pub per_page: usize,
+    pub results: Vec<SearchHit>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SearchHit {
+    pub hanja: char,
+    pub readings: Vec<char>,
+    pub meaning: Option<&'static str>,
+    pub radical: Option<char>,
+    pub extra_strokes: Option<u8>,
+    pub strokes: Option<u8>,
+    pub grade: Option<&'static str>,
+}
+
+impl SearchIndex {
+    pub fn build(char_dic: &phf::Map<char, char>, info_dic: &phf::Map<char, HanjaInfo>) -> SearchIndex {
+        // 1. 한자별 읽기: 한자 정보 사전의 읽기(순위 순서) -> 기본한자 사전의 읽기 -> 호환용 한자의 읽기
+        let mut readings: HashMap<char, Vec<char>> = HashMap::new();
+        for (hanja, info) in info_dic.entries() {
+            let list = readings.entry(*hanja).or_default();
+            for reading in info.readings {
+                push_unique(list, reading.hangul);
+            }
+        }
+        let mut compat = Vec::new();
+        for (hanja, reading) in char_dic.entries() {
+            match canonical_form(*hanja) {
+                Some(unified) => compat.push((unified, *reading)),
+                None => push_unique(readings.entry(*hanja).or_default(), *reading),
+            }
+        }
+        compat.sort();  // phf::Map의 순서는 정해져 있지 않으므로 정렬해서 읽기 순서를 고정한다.
+        for (unified, reading) in compat {
+            push_unique(readings.entry(unified).or_default(), reading);
+        }
+
+        // 2. 보조 색인
+        let mut by_reading: HashMap<char, Vec<char>> = HashMap::new();
+        for (hanja, list) in &readings {
+            for reading in list {
+                by_reading.entry(*reading).or_default().push(*hanja);
+            }
+        }
+        let mut by_radical: HashMap<char, Vec<char>> = HashMap::new();
+        let mut by_strokes: HashMap<u8, Vec<char>> = HashMap::new();
+        let mut by_grade: HashMap<&'static str, Vec<char>> = HashMap::new();
+        for (hanja, info) in info_dic.entries() {
+            if let Some(radical) = info.radical {
+                by_radical.entry(radical).or_default().push(*hanja);
+            }
+            if let Some(strokes) = info.strokes {
+                by_strokes.entry(strokes).or_default().push(*hanja);
+            }
+            if let Some(grade) = info.grade {
+                by_grade.entry(grade).or_default().push(*hanja);
+            }
+        }
+
+        // 3. 교육용 기초한자를 먼저, 확장 A(U+3400~)의 드물게 쓰는 한자를 나중에, 나머지는 코드 포인트 순서로 정렬한다.
+        let order = |c: &char| {
+            let edu = match info_dic.get(c).map(|info| info.edu) {
+                Some(EduLevel::Middle) => 0,
+                Some(EduLevel::High) => 1,
+                _ => 2,
+            };
+            (edu, (*c as u32) < 0x4E00, *c)
+        };
+        for list in by_reading.values_mut().chain(by_radical.values_mut()).chain(by_strokes.values_mut()).chain(by_grade.values_mut()) {
+            list.sort_by_key(order);
+        }
+
+        SearchIndex { readings, by_reading, by_radical, by_strokes, by_grade }
+    }
+}
+
+impl Dictionary {
+    // 조건이 하나도 없거나 형식이 잘못되었으면 Err
+    pub fn search(&self, query: &SearchQuery) -> Result<SearchPage, String> {
+        let reading = query.reading.as_deref().map(|s| single_char(s, "reading")).transpose()?;
+        let radical = query.radical.as_deref().map(|s| single_char(s, "radical")).transpose()?;
+        let grade = query.grade.as_deref().map(|s| s.trim());
+        if query.extra_strokes.is_some() && radical.is_none() {
+            return Err("extra_strokes는 radical과 함께 지정해야 합니다.".to_string());
+        }
+
+        // 1. 지정한 조건 중 하나의 색인에서 후보를 가져온다.
+        let index = &self.index;
+        let empty = Vec::new();
+        let candidates = if let Some(reading) = reading {
+            index.by_reading.get(&reading)
+        } else if let Some(radical) = radical {
+            index.by_radical.get(&radical)
+        } else if let Some(strokes) = query.strokes {
+            index.by_strokes.get(&strokes)
+        } else if let Some(grade) = grade {
+            index.by_grade.get(grade)
+        } else {
+            return Err("reading, radical, strokes, grade 중 하나 이상을 지정해야 합니다.".to_string());
+        }.unwrap_or(&empty);
+
+        // 2. 나머지 조건으로 거른다.
+        let matches = candidates.iter()
+            .filter(|c| reading.is_none_or(|r| index.readings.get(c).is_some_and(|list| list.contains(&r))))
+            .filter(|c| {
+                let info = self.info(**c);
+                radical.is_none_or(|r| info.and_then(|info| info.radical) == Some(r))
+                    && query.extra_strokes.is_none_or(|n| info.and_then(|info| info.extra_strokes) == Some(n))
+                    && query.strokes.is_none_or(|n| info.and_then(|info| info.strokes) == Some(n))
+                    && grade.is_none_or(|g| info.and_then(|info| info.grade) == Some(g))
+            })
+            .collect::<Vec<&char>>();
+
+        // 3. 페이지 나누기
+        let per_page = query.per_page.unwrap_or(DEFAULT_PER_PAGE).clamp(1, MAX_PER_PAGE);
+        let page = query.page.unwrap_or(1).max(1);
+        let results = matches.iter()
+            .skip((page - 1).saturating_mul(per_page))
+            .take(per_page)
+            .map(|c| self.search_hit(**c))
+            .collect();
+
+        Ok(SearchPage { total: matches.len(), page, per_page, results })
+    }
+
+    fn search_hit(&self, c: char) -> SearchHit {
+        let info = self.info(c);
+        SearchHit {
+            hanja: c,
+            readings: self.index.readings.get(&c).cloned().unwrap_or_default(),
+            meaning: info.map(|info| info.meaning).filter(|meaning| !meaning.is_empty()),
+            radical: info.and_then(|info| info.radical),
+            extra_strokes: info.and_then(|info| info.extra_strokes),
+            strokes: info.and_then(|info| info.strokes),
+            grade: info.and_then(|info| info.grade),
+        }
+    }
+}
+
+fn push_unique(list: &mut Vec<char>, c: char) {
+    if !list.contains(&c) {
+        list.push(c);
+    }
+}
+
+fn single_char(s: &str, field: &str) -> Result<char, String> {
+    let mut chars = s.trim().chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(c),
+        _ => Err(format!("{}는 한 글자여야 합니다: {:?}", field, s)),
+    }
+}
@@ -0,0 +1,64 @@
+// 모든 요청에 공통으로 붙이는 미들웨어: 요청 제한(시간, 동시 처리 수, 본문 크기), 압축, CORS
+
+use std::time::Duration;
+
+use axum::{
+    error_handling::HandleErrorLayer, extract::DefaultBodyLimit,
+    http::{header, HeaderName, HeaderValue, Method, StatusCode}, BoxError, Router
+};
+use tower::{limit::GlobalConcurrencyLimitLayer, load_shed::{error::Overloaded, LoadShedLayer}, timeout::{error::Elapsed, TimeoutLayer}, ServiceBuilder};
+use tower_http::{compression::CompressionLayer, cors::{AllowOrigin, CorsLayer}};
+
+use crate::Config;
+
+// router에 공통 미들웨어를 붙인다.
+// CORS를 가장 바깥에 두어야 시간 초과(408), 과부하(503), 본문 크기 초과(413) 응답에도 CORS 헤더가 붙어
+// 다른 사이트의 브라우저에서 오류 내용을 읽을 수 있다.
+pub fn with_common_layers(router: Router, config: &Config) -> Router {
+    router
+    .layer(CompressionLayer::new())
+    .layer(DefaultBodyLimit::max(config.default_body_limit))
+    // 요청 제한: 바깥쪽부터 오류 처리 -> 과부하 차단 -> 동시 처리 수 제한 -> 시간 제한
+    .layer(
+        ServiceBuilder::new()
+            .layer(HandleErrorLayer::new(handle_middleware_error))
+            .layer(LoadShedLayer::new())
+            .layer(GlobalConcurrencyLimitLayer::new(config.concurrency_limit))
+            .layer(TimeoutLayer::new(Duration::from_secs(config.request_timeout_secs)))
+    )
+    .layer(cors_layer(config))
+}
+
+// 다른 사이트의 브라우저에서 /convert 등을 직접 호출할 수 있도록 설정에 있는 origin만 허용한다.
+fn cors_layer(config: &Config) -> CorsLayer {
+    let origins = config.cors_allowed_origins.iter()
+        .filter_map(|origin| match HeaderValue::from_str(origin) {
+            Ok(value) => Some(value),
+            Err(e) => { log::error!("잘못된 CORS origin {}: {}", origin, e); None }
+        })
+        .collect::<Vec<HeaderValue>>();
+    let methods = config.cors_allowed_methods.iter()
+        .filter_map(|method| method.parse::<Method>().ok())
+        .collect::<Vec<Method>>();
+    let headers = config.cors_allowed_headers.iter()
+        .filter_map(|name| name.parse::<HeaderName>().ok())
+        .collect::<Vec<HeaderName>>();
+
+    CorsLayer::new()
+        .allow_origin(AllowOrigin::list(origins))
+        .allow_methods(methods)
+        .allow_headers(headers)
+        .expose_headers([header::RETRY_AFTER, header::ETAG])
+}
+
+// 시간 초과, 과부하 등 미들웨어에서 발생한 오류를 HTTP 응답으로 바꾼다.
+async fn handle_middleware_error(err: BoxError) -> (StatusCode, &'static str) {
+    if err.is::<Elapsed>() {
+        (StatusCode::REQUEST_TIMEOUT, "요청 처리 시간이 초과되었습니다.")
+    } else if err.is::<Overloaded>() {
+        (StatusCode::SERVICE_UNAVAILABLE, "서버가 혼잡합니다. 잠시 후 다시 시도해 주세요.")
+    } else {
+        log::error!("Unhandled middleware error: {}", err);
+        (StatusCode::INTERNAL_SERVER_ERROR, "서버 내부 오류가 발생했습니다.")
+    }
+}
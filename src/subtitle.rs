@@ -0,0 +1,229 @@
+// 자막 파일(SRT, WebVTT)의 대사만 변환한다.
+// 자막을 큐(cue) 단위로 읽고, 번호/식별자와 시간 줄(큐 설정 포함)은 그대로 둔 채 대사 줄만 변환해서 다시 쓴다.
+// WebVTT의 머리말(WEBVTT), NOTE, STYLE, REGION 블록과 큐로 읽을 수 없는 블록도 그대로 쓴다.
+// 대사 안의 태그(<i>, <c.yellow>, <v 이름> 등)는 HTML과 같이 처리한다(html.rs 참조).
+// 변환해서 한 줄이 설정한 너비를 넘게 되면 경고한다.
+
+use serde::{Deserialize, Serialize};
+
+use crate::conversion;
+use crate::html::HtmlOptions;
+use crate::{is_chi, ConvertOptions, Dictionary, MappedChar, OutputMode};
+
+// 한 줄의 기본 최대 너비. 한글, 한자는 2칸, 영문, 숫자는 1칸으로 센다.
+pub const DEFAULT_MAX_WIDTH: usize = 40;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SubtitleFormat {
+    Srt,
+    Vtt,
+}
+
+impl SubtitleFormat {
+    // WEBVTT로 시작하면 WebVTT, 아니면 SRT
+    pub fn detect(text: &str) -> SubtitleFormat {
+        if text.trim_start_matches('\u{FEFF}').starts_with("WEBVTT") {
+            SubtitleFormat::Vtt
+        } else {
+            SubtitleFormat::Srt
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Subtitle {
+    pub format: SubtitleFormat,
+    pub blocks: Vec<Block>,
+    bom: bool,
+    newline: &'static str,   // 입력의 줄바꿈("\r\n" 또는 "\n")
+    trailing_newline: bool,  // 입력이 줄바꿈으로 끝나는지
+}
+
+#[derive(Debug, Clone)]
+pub enum Block {
+    Cue(Cue),
+    Other(Vec<String>),  // 머리말, NOTE 등 그대로 쓰는 블록
+}
+
+#[derive(Debug, Clone)]
+pub struct Cue {
+    pub id: Option<String>,  // SRT의 번호, WebVTT의 식별자
+    pub timing: String,      // "00:00:01,000 --> 00:00:04,000" (WebVTT는 큐 설정 포함)
+    pub lines: Vec<String>,  // 대사
+}
+
+// 변환해서 설정한 너비를 넘게 된 줄
+#[derive(Debug, Clone, Serialize)]
+pub struct WidthWarning {
+    pub cue: usize,   // 몇 번째 큐인지 (1부터)
+    pub line: usize,  // 큐 안에서 몇 번째 줄인지 (1부터)
+    pub width: usize,
+    pub max_width: usize,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SubtitleConversion {
+    pub format: SubtitleFormat,
+    pub subtitle: String,
+    pub converted: bool,  // 사전에서 찾아 변환한 한자가 하나라도 있는지
+    pub warnings: Vec<WidthWarning>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub unknown: Vec<char>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub mapped: Vec<MappedChar>,
+}
+
+impl Subtitle {
+    pub fn parse(text: &str, format: SubtitleFormat) -> Subtitle {
+        let bom = text.starts_with('\u{FEFF}');
+        let text = text.trim_start_matches('\u{FEFF}');
+
+        // 빈 줄로 블록을 나눈다.
+        let mut groups: Vec<Vec<String>> = Vec::new();
+        let mut group: Vec<String> = Vec::new();
+        for line in text.lines() {
+            if line.trim().is_empty() {
+                if !group.is_empty() {
+                    groups.push(std::mem::take(&mut group));
+                }
+            } else {
+                group.push(line.to_string());
+            }
+        }
+        if !group.is_empty() {
+            groups.push(group);
+        }
+
+        let blocks = groups.into_iter().map(|lines| parse_block(lines, format)).collect();
+        Subtitle {
+            format,
+            blocks,
+            bom,
+            newline: if text.contains("\r\n") { "\r\n" } else { "\n" },
+            trailing_newline: text.ends_with('\n'),
+        }
+    }
+
+    // 블록 사이에는 빈 줄 하나를 넣는다.
+    pub fn write(&self) -> String {
+        let blocks = self.blocks.iter()
+            .map(|block| match block {
+                Block::Cue(cue) => cue.id.iter().chain(std::iter::once(&cue.timing)).chain(cue.lines.iter())
+                    .map(|line| line.as_str())
+                    .collect::<Vec<&str>>()
+                    .join(self.newline),
+                Block::Other(lines) => lines.join(self.newline),
+            })
+            .collect::<Vec<String>>();
+
+        let mut buf = String::new();
+        if self.bom {
+            buf.push('\u{FEFF}');
+        }
+        buf.push_str(&blocks.join(&format!("{0}{0}", self.newline)));
+        if self.trailing_newline {
+            buf.push_str(self.newline);
+        }
+        buf
+    }
+}
+
+// 처음 두 줄 안에 시간 줄("-->")이 있으면 큐, 아니면 그대로 쓰는 블록
+fn parse_block(mut lines: Vec<String>, format: SubtitleFormat) -> Block {
+    let first = lines[0].as_str();
+    if format == SubtitleFormat::Vtt && ["WEBVTT", "NOTE", "STYLE", "REGION"].iter().any(|keyword| first.starts_with(keyword)) {
+        return Block::Other(lines);
+    }
+    match lines.iter().take(2).position(|line| line.contains("-->")) {
+        Some(i) => {
+            let text = lines.split_off(i + 1);
+            let timing = lines.pop().unwrap_or_default();
+            Block::Cue(Cue { id: lines.pop(), timing, lines: text })
+        },
+        None => Block::Other(lines),
+    }
+}
+
+impl Dictionary {
+    // SRT는 <ruby>를 표시할 수 없으므로 ruby 형식이면 Err
+    pub fn convert_subtitle(&self, text: &str, format: SubtitleFormat, options: &ConvertOptions, max_width: usize) -> Result<SubtitleConversion, String> {
+        if format == SubtitleFormat::Srt && options.mode == OutputMode::Ruby {
+            return Err("SRT 자막은 ruby 형식을 지원하지 않습니다. WebVTT를 사용하거나 다른 출력 형식을 지정해 주세요.".to_string());
+        }
+
+        let mut subtitle = Subtitle::parse(text, format);
+        let mut result = SubtitleConversion {
+            format,
+            subtitle: String::new(),
+            converted: false,
+            warnings: Vec::new(),
+            unknown: Vec::new(),
+            mapped: Vec::new(),
+        };
+        let html = HtmlOptions::default();
+        let cues = subtitle.blocks.iter_mut().filter_map(|block| match block {
+            Block::Cue(cue) => Some(cue),
+            Block::Other(_) => None,
+        });
+        for (n, cue) in cues.enumerate() {
+            // 1. 큐의 대사 전체를 한 번에 변환한다.
+            let conversion = self.convert_html(&cue.lines.join("\n"), options, &html);
+            result.converted |= conversion.is_converted();
+            conversion::extend_unique(&mut result.unknown, conversion.unknown());
+            conversion::extend_unique(&mut result.mapped, conversion.mapped());
+            let converted = conversion.render(options.mode).split('\n').map(|line| line.to_string()).collect::<Vec<String>>();
+
+            // 2. 변환하기 전에는 너비 안에 있던 줄이 넘치면 경고한다.
+            for (i, (before, after)) in cue.lines.iter().zip(converted.iter()).enumerate() {
+                let width = display_width(after);
+                if width > max_width && display_width(before) <= max_width {
+                    result.warnings.push(WidthWarning { cue: n + 1, line: i + 1, width, max_width, text: after.clone() });
+                }
+            }
+            cue.lines = converted;
+        }
+        result.subtitle = subtitle.write();
+        Ok(result)
+    }
+}
+
+// 화면에 보이는 너비. 태그와 <rt>의 읽기는 세지 않는다.
+pub fn display_width(line: &str) -> usize {
+    let mut width = 0;
+    let mut tag: Option<String> = None;
+    let mut in_rt = false;
+    for c in line.chars() {
+        if let Some(name) = &mut tag {
+            if c != '>' {
+                name.push(c);
+                continue;
+            }
+            match name.split(|c: char| c.is_whitespace() || c == '.').next().unwrap_or("").to_ascii_lowercase().as_str() {
+                "rt" => in_rt = true,
+                "/rt" => in_rt = false,
+                _ => {},
+            }
+            tag = None;
+        } else if c == '<' {
+            tag = Some(String::new());
+        } else if !in_rt {
+            width += char_width(c);
+        }
+    }
+    width
+}
+
+// 한글, 한자, 가나, 전각 문자는 2칸
+fn char_width(c: char) -> usize {
+    let n = c as u32;
+    let wide = is_chi(&c)
+        || (0x1100..=0x115F).contains(&n)  // 한글 자모(초성)
+        || (0x3000..=0x30FF).contains(&n)  // CJK 기호, 가나
+        || (0x3130..=0x318F).contains(&n)  // 한글 호환 자모
+        || (0xAC00..=0xD7A3).contains(&n)  // 한글 음절
+        || (0xFF01..=0xFF60).contains(&n)  // 전각 문자
+        || (0xFFE0..=0xFFE6).contains(&n);
+    if wide { 2 } else { 1 }
+}
@@ -0,0 +1,196 @@
+// CSV, TSV 표에서 지정한 열의 한자만 변환한다.
+// 열은 머리글의 이름이나 1부터 세는 번호로 지정한다. 머리글에 같은 이름의 열이 없을 때만 번호로 본다.
+// 변환한 값으로 원래 열을 바꾸거나, 표의 끝에 "<열 이름>_hangul" 열로 붙인다.
+// report를 켜면 행마다 지정한 열에서 찾은 사전에 없는 한자의 수를 unknown_hanja 열로 붙인다.
+// 따옴표, 필드 안의 구분자와 줄바꿈은 csv crate로 읽고 쓰며, 출력은 필요한 필드만 따옴표로 감싼다.
+
+use csv::{ReaderBuilder, StringRecord, Terminator, WriterBuilder};
+use serde::{Deserialize, Serialize};
+
+use crate::conversion;
+use crate::{ConvertOptions, Dictionary, MappedChar, SpanKind};
+
+// 붙이는 열의 이름
+pub const APPEND_SUFFIX: &str = "_hangul";
+pub const REPORT_COLUMN: &str = "unknown_hanja";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TableFormat {
+    Csv,
+    Tsv,
+}
+
+impl TableFormat {
+    // 파일 이름의 확장자로 판단한다. .tsv, .tab이면 TSV, 아니면 CSV
+    pub fn from_file_name(name: &str) -> TableFormat {
+        let extension = name.rsplit_once('.').map(|(_, ext)| ext.to_ascii_lowercase()).unwrap_or_default();
+        match extension.as_str() {
+            "tsv" | "tab" => TableFormat::Tsv,
+            _ => TableFormat::Csv,
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            TableFormat::Csv => "text/csv; charset=utf-8",
+            TableFormat::Tsv => "text/tab-separated-values; charset=utf-8",
+        }
+    }
+
+    fn delimiter(&self) -> u8 {
+        match self {
+            TableFormat::Csv => b',',
+            TableFormat::Tsv => b'\t',
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TableOptions {
+    pub columns: Vec<String>,  // 변환할 열의 이름 또는 번호(1부터)
+    pub header: bool,          // 첫 행이 머리글인지
+    pub append: bool,          // 원래 열을 두고 변환한 값을 새 열로 붙일지
+    pub report: bool,          // 행마다 사전에 없는 한자의 수를 붙일지
+}
+
+impl Default for TableOptions {
+    fn default() -> TableOptions {
+        TableOptions {
+            columns: Vec::new(),
+            header: true,
+            append: false,
+            report: false,
+        }
+    }
+}
+
+impl TableOptions {
+    // 캐시 key, ETag 계산에 사용하는 옵션의 문자열 표현
+    pub fn key(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TableConversion {
+    pub table: String,
+    pub rows: usize,      // 머리글을 뺀 행 수
+    pub converted: bool,  // 사전에서 찾아 변환한 한자가 하나라도 있는지
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub unknown: Vec<char>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub mapped: Vec<MappedChar>,
+}
+
+impl Dictionary {
+    // 열을 지정하지 않았거나, 없는 열을 지정했거나, 표를 읽을 수 없으면 Err
+    pub fn convert_table(&self, text: &str, format: TableFormat, table: &TableOptions, options: &ConvertOptions) -> Result<TableConversion, String> {
+        let bom = text.starts_with('\u{FEFF}');
+        let text = text.trim_start_matches('\u{FEFF}');
+
+        // 1. 표 전체를 읽는다. 행마다 열의 수가 달라도 된다.
+        let mut records = ReaderBuilder::new()
+            .delimiter(format.delimiter())
+            .has_headers(false)
+            .flexible(true)
+            .from_reader(text.as_bytes())
+            .into_records()
+            .collect::<Result<Vec<StringRecord>, csv::Error>>()
+            .map_err(|e| format!("표를 읽을 수 없습니다: {}", e))?;
+        let header = if table.header && !records.is_empty() { Some(records.remove(0)) } else { None };
+        let columns = resolve_columns(&table.columns, header.as_ref())?;
+
+        // 2. 새 열을 붙이면 짧은 행을 머리글(없으면 가장 긴 행)의 열 수만큼 채워서 붙인 열의 위치를 맞춘다.
+        let width = header.as_ref().map_or_else(|| records.iter().map(|r| r.len()).max().unwrap_or(0), |h| h.len());
+        let pads = table.append || table.report;
+
+        let terminator = if text.contains("\r\n") { Terminator::CRLF } else { Terminator::Any(b'\n') };
+        let mut writer = WriterBuilder::new()
+            .delimiter(format.delimiter())
+            .terminator(terminator)
+            .flexible(true)
+            .from_writer(Vec::new());
+        let write_error = |e: csv::Error| format!("표를 쓸 수 없습니다: {}", e);
+
+        if let Some(header) = &header {
+            let mut fields = header.iter().map(|field| field.to_string()).collect::<Vec<String>>();
+            if table.append {
+                fields.extend(columns.iter().map(|&col| format!("{}{}", header.get(col).unwrap_or(""), APPEND_SUFFIX)));
+            }
+            if table.report {
+                fields.push(REPORT_COLUMN.to_string());
+            }
+            writer.write_record(&fields).map_err(write_error)?;
+        }
+
+        // 3. 행마다 지정한 열만 변환한다.
+        let mut result = TableConversion { table: String::new(), rows: records.len(), converted: false, unknown: Vec::new(), mapped: Vec::new() };
+        for record in &records {
+            let mut fields = record.iter().map(|field| field.to_string()).collect::<Vec<String>>();
+            if pads && fields.len() < width {
+                fields.resize(width, String::new());
+            }
+            let mut appended = Vec::new();
+            let mut unknown = 0;
+            for &col in &columns {
+                let conversion = self.convert(record.get(col).unwrap_or(""), options);
+                result.converted |= conversion.is_converted();
+                unknown += conversion.spans.iter()
+                    .filter(|span| span.kind == SpanKind::Unknown)
+                    .map(|span| span.source.chars().count())
+                    .sum::<usize>();
+                conversion::extend_unique(&mut result.unknown, conversion.unknown());
+                conversion::extend_unique(&mut result.mapped, conversion.mapped());
+
+                let converted = conversion.render(options.mode);
+                if table.append {
+                    appended.push(converted);
+                } else if col < fields.len() {
+                    fields[col] = converted;
+                }
+            }
+            fields.extend(appended);
+            if table.report {
+                fields.push(unknown.to_string());
+            }
+            writer.write_record(&fields).map_err(write_error)?;
+        }
+
+        let bytes = writer.into_inner().map_err(|e| format!("표를 쓸 수 없습니다: {}", e.error()))?;
+        let mut written = String::from_utf8(bytes).map_err(|e| e.to_string())?;
+        // 입력이 줄바꿈으로 끝나지 않으면 마지막 줄바꿈을 뺀다.
+        if !text.ends_with('\n') {
+            let len = written.trim_end_matches(['\r', '\n']).len();
+            written.truncate(len);
+        }
+        if bom {
+            written.insert(0, '\u{FEFF}');
+        }
+        result.table = written;
+        Ok(result)
+    }
+}
+
+// 열 이름과 번호를 0부터 세는 열 위치로 바꾼다. 같은 열을 두 번 지정하면 한 번만 변환한다.
+fn resolve_columns(columns: &[String], header: Option<&StringRecord>) -> Result<Vec<usize>, String> {
+    if columns.is_empty() {
+        return Err("변환할 열을 지정해 주세요.".to_string());
+    }
+    let mut resolved: Vec<usize> = Vec::new();
+    for column in columns {
+        let column = column.trim();
+        let by_name = header.and_then(|header| header.iter().position(|name| name.trim() == column));
+        let by_index = || column.parse::<usize>().ok()
+            .filter(|&n| n >= 1 && header.is_none_or(|header| n <= header.len()))
+            .map(|n| n - 1);
+        let Some(col) = by_name.or_else(by_index) else {
+            return Err(format!("없는 열: {}", column));
+        };
+        if !resolved.contains(&col) {
+            resolved.push(col);
+        }
+    }
+    Ok(resolved)
+}
@@ -0,0 +1,90 @@
+// API 키 인증과 요청 속도 제한
+// 키 별, IP 별 토큰 버킷과 알 수 없는 키로 보낸 요청의 처리를 확인한다.
+
+use rust_web::access::{AccessControl, ApiKeys, Denied, RateLimiter};
+
+fn access_control() -> AccessControl {
+    AccessControl {
+        api_keys: ApiKeys::parse("# 클라이언트이름,API키\nnews,key-1\n\n잘못된 줄\n"),
+        key_limiter: RateLimiter::new(60, 3),
+        anon_limiter: RateLimiter::new(60, 2),
+    }
+}
+
+#[test]
+fn api_keys_file() {
+    let keys = ApiKeys::parse("# 주석\nnews,key-1\nblog , key-2 \n이름만,\n");
+    assert_eq!(keys.len(), 2);
+    assert_eq!(keys.client_name("key-1"), Some("news"));
+    assert_eq!(keys.client_name("key-2"), Some("blog"));
+    assert_eq!(keys.client_name("이름만"), None);
+}
+
+#[test]
+fn burst_then_limited() {
+    let limiter = RateLimiter::new(60, 2);
+    assert!(limiter.check("a").is_ok());
+    assert!(limiter.check("a").is_ok());
+    let wait = limiter.check("a").unwrap_err();
+    assert!(wait.as_secs_f64() > 0.0 && wait.as_secs_f64() <= 1.0, "{:?}", wait);
+    // 버킷은 클라이언트마다 따로 있다.
+    assert!(limiter.check("b").is_ok());
+}
+
+#[test]
+fn key_and_anonymous_buckets() {
+    let access = access_control();
+    for _ in 0..3 {
+        assert_eq!(access.check(Some("Bearer key-1"), "10.0.0.1"), Ok(()));
+    }
+    assert!(matches!(access.check(Some("Bearer key-1"), "10.0.0.1"), Err(Denied::TooManyRequests(_))));
+    // 키의 제한은 같은 IP의 키 없는 요청에 영향을 주지 않는다.
+    assert_eq!(access.check(None, "10.0.0.1"), Ok(()));
+}
+
+// 알 수 없는 키로 보낸 요청은 IP 별 제한에 포함되고, 제한을 넘으면 401 대신 429가 된다.
+#[test]
+fn invalid_keys_are_rate_limited() {
+    let access = access_control();
+    assert_eq!(access.check(Some("Bearer guess-1"), "10.0.0.2"), Err(Denied::InvalidKey));
+    assert_eq!(access.check(Some("guess-2"), "10.0.0.2"), Err(Denied::InvalidKey));
+    assert!(matches!(access.check(Some("Bearer guess-3"), "10.0.0.2"), Err(Denied::TooManyRequests(_))));
+    assert!(matches!(access.check(None, "10.0.0.2"), Err(Denied::TooManyRequests(_))));
+    // 다른 IP와 올바른 키는 그대로 사용할 수 있다.
+    assert_eq!(access.check(Some("Bearer guess-4"), "10.0.0.3"), Err(Denied::InvalidKey));
+    assert_eq!(access.check(Some("Bearer key-1"), "10.0.0.2"), Ok(()));
+}
+
+// 운영용 API는 키가 없으면 거절하고, 키 없는 요청도 IP 별 제한에 포함한다.
+#[test]
+fn key_required() {
+    let access = access_control();
+    assert_eq!(access.check_key(Some("Bearer key-1"), "10.0.0.4"), Ok(()));
+    assert_eq!(access.check_key(None, "10.0.0.4"), Err(Denied::InvalidKey));
+    assert_eq!(access.check_key(Some("Bearer guess"), "10.0.0.4"), Err(Denied::InvalidKey));
+    assert!(matches!(access.check_key(None, "10.0.0.4"), Err(Denied::TooManyRequests(_))));
+}
+
+// 인증 방식(Bearer)은 대소문자를 구분하지 않는다.
+#[test]
+fn bearer_scheme_case_insensitive() {
+    let access = access_control();
+    assert_eq!(access.check(Some("bearer key-1"), "10.0.0.5"), Ok(()));
+    assert_eq!(access.check(Some("BEARER  key-1 "), "10.0.0.5"), Ok(()));
+    assert_eq!(access.check(Some("Basic key-1"), "10.0.0.5"), Err(Denied::InvalidKey));
+}
+
+// 버킷 수는 상한을 넘지 않고, 가장 오래 사용되지 않은 클라이언트의 버킷부터 지운다.
+#[test]
+fn max_clients() {
+    let limiter = RateLimiter::with_max_clients(60, 1, 2);
+    assert!(limiter.check("a").is_ok());
+    assert!(limiter.check("b").is_ok());
+    assert!(limiter.check("a").is_err());
+    assert!(limiter.check("c").is_ok());
+    assert_eq!(limiter.len(), 2);
+    // b의 버킷이 지워졌으므로 새 버킷으로 시작하고, a의 버킷은 남아 있다.
+    assert!(limiter.check("b").is_ok());
+    assert_eq!(limiter.len(), 2);
+    assert!(limiter.check("c").is_err());
+}
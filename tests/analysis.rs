@@ -0,0 +1,51 @@
+// 한자 어휘 분석 (POST /analyze)
+// 단어와 한자 한 글자의 횟수, 순서, 급수, CSV 출력을 확인한다.
+
+use rust_web::analysis::{Analysis, VocabEntry};
+use rust_web::ConvertOptions;
+
+fn analyze(text: &str) -> Analysis {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    dic.analyze(text, &ConvertOptions::default())
+}
+
+fn entries(list: &[VocabEntry]) -> Vec<(&str, &str, usize)> {
+    list.iter().map(|entry| (entry.hanja.as_str(), entry.reading.as_str(), entry.count)).collect()
+}
+
+#[test]
+fn words_and_chars() {
+    let analysis = analyze("金氏는 大韓民國 國民이다. 大韓民國의 金氏, 學校");
+    assert_eq!(entries(&analysis.words), [("金氏", "김씨", 2), ("大韓民國", "대한민국", 2), ("國民", "국민", 1), ("學校", "학교", 1)]);
+    assert_eq!(analysis.total_words, 6);
+    assert_eq!(analysis.total_chars, 16);
+    // 民과 國은 大韓民國에 두 번, 國民에 한 번 나온다. 횟수가 같으면 먼저 나온 순서
+    let first = analysis.chars.iter().take(3).map(|entry| (entry.hanja.as_str(), entry.count)).collect::<Vec<_>>();
+    assert_eq!(first, [("民", 3), ("國", 3), ("金", 2)]);
+}
+
+// 한 글자는 단어로 세지 않는다.
+#[test]
+fn single_chars_are_not_words() {
+    let analysis = analyze("山 水");
+    assert!(analysis.words.is_empty());
+    assert_eq!(entries(&analysis.chars), [("山", "산", 1), ("水", "수", 1)]);
+}
+
+// 단어의 급수는 가장 어려운 한자의 급수이고, 급수를 모르는 한자가 있으면 없다.
+#[test]
+fn grades() {
+    let analysis = analyze("學校 校門 伽藍");
+    let grade = |hanja: &str| analysis.words.iter().find(|entry| entry.hanja == hanja).unwrap().grade;
+    assert_eq!(grade("校門"), Some("8급"));
+    assert_eq!(grade("伽藍"), None);
+}
+
+#[test]
+fn csv() {
+    let csv = analyze("金氏, \"金氏\"").to_csv();
+    let mut lines = csv.split("\r\n");
+    assert_eq!(lines.next(), Some("kind,hanja,reading,count,grade"));
+    assert_eq!(lines.next(), Some("word,金氏,김씨,2,"));  // 氏는 급수를 모른다.
+    assert!(csv.contains("char,金,"));
+}
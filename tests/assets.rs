@@ -0,0 +1,40 @@
+// 실행 파일에 넣어 둔 index.html, css, js
+// 목록에 있는 파일만 제공하고, 디스크에서 읽을 때도 같은 목록과 Content-Type을 쓰는지 확인한다.
+
+use rust_web::assets::Assets;
+
+#[tokio::test]
+async fn embedded() {
+    let assets = Assets::new(false);
+    assert!(!assets.from_disk());
+    for (path, content_type) in [
+        ("index.html", "text/html; charset=utf-8"),
+        ("css/main.css", "text/css; charset=utf-8"),
+        ("js/main.js", "text/javascript; charset=utf-8"),
+    ] {
+        let asset = assets.get(path).await.unwrap();
+        assert_eq!(asset.content_type, content_type, "{}", path);
+        assert_eq!(asset.body.as_ref(), std::fs::read(path).unwrap().as_slice(), "{}", path);
+    }
+}
+
+// 목록에 없는 파일은 디스크에 있어도 제공하지 않는다.
+#[tokio::test]
+async fn only_listed_files() {
+    for from_disk in [false, true] {
+        let assets = Assets::new(from_disk);
+        for path in ["Cargo.toml", "../index.html", "src/lib.rs", "css/../index.html"] {
+            assert!(assets.get(path).await.is_none(), "{} {}", path, from_disk);
+        }
+    }
+}
+
+#[tokio::test]
+async fn from_disk() {
+    let assets = Assets::new(true);
+    let embedded = Assets::new(false);
+    let index = assets.get("index.html").await.unwrap();
+    assert_eq!(index.content_type, "text/html; charset=utf-8");
+    // 파일을 고치지 않았으면 ETag도 같다.
+    assert_eq!(index.etag, embedded.get("index.html").await.unwrap().etag);
+}
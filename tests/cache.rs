@@ -0,0 +1,62 @@
+// 변환 결과 LRU 캐시
+// 크기(byte) 제한, 오래 사용하지 않은 항목부터 지우기, 사전 버전이 바뀌면 비우기를 확인한다.
+
+use rust_web::cache::{CachedConversion, ConversionCache};
+
+fn value(text: &str) -> CachedConversion {
+    CachedConversion {
+        converted_text: Some(text.to_string()),
+        mapped: Vec::new(),
+        romanized: None,
+        pronunciations: Vec::new(),
+    }
+}
+
+#[test]
+fn hit_and_miss() {
+    let cache = ConversionCache::new(64 * 1024);
+    assert!(cache.get("v1", "a").is_none());
+    cache.insert("v1", "a".to_string(), value("대한민국"));
+    assert_eq!(cache.get("v1", "a").unwrap().converted_text.as_deref(), Some("대한민국"));
+
+    let stats = cache.stats();
+    assert_eq!((stats.hits, stats.misses, stats.entries), (1, 1, 1));
+    assert!(stats.bytes > 0 && stats.bytes <= stats.capacity);
+}
+
+#[test]
+fn evicts_least_recently_used() {
+    // 캐시 크기의 1/16까지만 보관하므로, 크기가 같은 항목은 16개까지 들어간다.
+    let text = "가".repeat(40);
+    let cache = ConversionCache::new(1 << 20);
+    cache.insert("v1", "k00".to_string(), value(&text));
+    let size = cache.stats().bytes;
+
+    let cache = ConversionCache::new(16 * size);
+    for i in 0..16 {
+        cache.insert("v1", format!("k{:02}", i), value(&text));
+    }
+    assert_eq!(cache.stats().entries, 16);
+    assert!(cache.get("v1", "k00").is_some());  // k00을 최근에 사용한 항목으로 만든다.
+    cache.insert("v1", "k16".to_string(), value(&text));
+
+    assert!(cache.get("v1", "k01").is_none());
+    assert!(cache.get("v1", "k00").is_some());
+    assert!(cache.get("v1", "k16").is_some());
+    assert_eq!(cache.stats().bytes, 16 * size);
+}
+
+#[test]
+fn skips_large_values() {
+    let cache = ConversionCache::new(16 * 100);
+    cache.insert("v1", "a".to_string(), value(&"가".repeat(100)));
+    assert_eq!(cache.stats().entries, 0);
+}
+
+#[test]
+fn cleared_when_dictionary_changes() {
+    let cache = ConversionCache::new(64 * 1024);
+    cache.insert("v1", "a".to_string(), value("대한민국"));
+    assert!(cache.get("v2", "a").is_none());
+    assert_eq!(cache.stats().entries, 0);
+}
@@ -0,0 +1,84 @@
+// 한자 한 글자의 정보 (GET /api/char/{c})
+// 읽기와 훈, 호환용 한자와 통합 한자의 관계, 이체자, 불규칙 변환 단어, 한자가 아닌 입력을 확인한다.
+
+use rust_web::char_info::{canonical_form, compatibility_forms, unicode_block};
+
+#[test]
+fn canonical_forms() {
+    assert_eq!(canonical_form('\u{F914}'), Some('樂'));  // 호환용 樂(락)
+    assert_eq!(canonical_form('\u{2F800}'), Some('丽'));
+    assert_eq!(canonical_form('樂'), None);
+    // 한자가 아닌 글자는 분해되더라도 None
+    assert_eq!(canonical_form('가'), None);
+    assert_eq!(canonical_form('é'), None);
+    assert_eq!(canonical_form('A'), None);
+}
+
+#[test]
+fn compatibility() {
+    assert!(compatibility_forms('樂').contains(&'\u{F914}'));
+    assert!(compatibility_forms('가').is_empty());
+    assert_eq!(unicode_block('樂'), Some("CJK Unified Ideographs"));
+    assert_eq!(unicode_block('\u{20000}'), Some("CJK Unified Ideographs Extension B"));
+    assert_eq!(unicode_block('가'), None);
+}
+
+#[test]
+fn detail_with_info() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    let detail = dic.char_detail('車').unwrap();
+    assert_eq!(detail.codepoint, "U+8ECA");
+    assert!(detail.has_info);
+    assert_eq!(detail.readings.iter().map(|r| r.hangul).collect::<String>(), "거차");
+    assert_eq!(detail.meaning, Some("수레/수레"));
+    assert_eq!((detail.radical, detail.strokes, detail.grade), (Some('車'), Some(7), Some("7급II")));
+    assert!(detail.words.iter().any(|word| word.hanja == "客車" && word.hangul == "객차"));
+    assert!(detail.compatibility.iter().any(|c| c.hanja == '\u{F902}'));
+}
+
+// 한자 정보 사전에 없는 한자는 읽기만 있다.
+#[test]
+fn detail_without_info() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    let detail = dic.char_detail('伽').unwrap();
+    assert!(!detail.has_info);
+    assert_eq!(detail.reading, Some('가'));
+    assert_eq!((detail.meaning, detail.radical, detail.strokes, detail.grade), (None, None, None, None));
+}
+
+// 호환용 한자는 자신의 읽기와 통합 한자의 정보를 함께 보여 준다.
+#[test]
+fn detail_of_compatibility_ideograph() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    let detail = dic.char_detail('\u{F914}').unwrap();
+    assert_eq!(detail.canonical.as_ref().map(|c| c.hanja), Some('樂'));
+    assert_eq!(detail.reading, Some('낙'));
+    assert_eq!(detail.strokes, Some(15));
+}
+
+// 사전에 호환용 한자로 적은 단어도 통합 한자와 호환용 한자 양쪽에서 보여 준다. (降 U+FA09 항)
+#[test]
+fn words_with_compatibility_ideographs() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    for c in ['降', '\u{FA09}'] {
+        let detail = dic.char_detail(c).unwrap();
+        let words = detail.words.iter().map(|word| word.hanja).collect::<Vec<&str>>();
+        for word in ["\u{FA09}伏", "\u{FA09}服", "投\u{FA09}"] {
+            assert!(words.contains(&word), "{} {}", c, word);
+        }
+    }
+}
+
+#[test]
+fn variants() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    assert!(dic.char_detail('峰').unwrap().variants.iter().any(|v| v.hanja == '峯'));
+    assert!(dic.char_detail('峯').unwrap().variants.iter().any(|v| v.hanja == '峰'));
+}
+
+#[test]
+fn not_hanja() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    assert!(dic.char_detail('가').is_none());
+    assert!(dic.char_detail('A').is_none());
+}
@@ -0,0 +1,91 @@
+// 명령행 변환기 (rust_web convert)
+// 표준 입력과 파일 변환, 옵션, 종료 코드, 출력 디렉토리를 실행 파일로 확인한다.
+
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Output, Stdio};
+
+fn run(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_rust_web"))
+        .arg("convert")
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(stdin.as_bytes()).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> &str {
+    std::str::from_utf8(&output.stdout).unwrap()
+}
+
+// 테스트마다 다른 임시 디렉토리
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("rust_web_cli_{}_{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn stdin() {
+    let output = run(&[], "大韓民國 金氏\n");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "대한민국 김씨\n");
+
+    let output = run(&["--mode", "hanja_hangul", "--no-dueum", "-"], "年末");
+    assert_eq!(stdout(&output), "年末(년말)");
+}
+
+#[test]
+fn formats() {
+    assert_eq!(stdout(&run(&["--format", "html"], "<b title=\"學校\">學校</b>")), "<b title=\"學校\">학교</b>");
+    assert_eq!(stdout(&run(&["--format", "csv", "--columns", "memo"], "name,memo\n金氏,學校\n")), "name,memo\n金氏,학교\n");
+    assert_eq!(stdout(&run(&["--romanize", "rr"], "學校")), "hakgyo");
+}
+
+// --strict는 사전에 없는 한자가 있으면 종료 코드 2로 끝난다.
+#[test]
+fn strict() {
+    let output = run(&["--strict"], "\u{20000}山");
+    assert_eq!(output.status.code(), Some(2));
+    assert_eq!(stdout(&output), "\u{20000}산");
+    assert!(String::from_utf8_lossy(&output.stderr).contains('\u{20000}'));
+
+    assert_eq!(run(&[], "\u{20000}山").status.code(), Some(0));
+}
+
+#[test]
+fn bad_arguments() {
+    assert_eq!(run(&["--mode", "braille"], "").status.code(), Some(1));
+    assert_eq!(run(&["no_such_file.txt"], "").status.code(), Some(1));
+    let help = run(&["--help"], "");
+    assert_eq!(help.status.code(), Some(0));
+    assert!(stdout(&help).starts_with("사용법"));
+}
+
+#[test]
+fn output_dir() {
+    let dir = temp_dir("output");
+    let input = dir.join("chapter1.txt");
+    std::fs::write(&input, "學校").unwrap();
+    let out = dir.join("out");
+    let output = run(&["-o", out.to_str().unwrap(), input.to_str().unwrap(), "-"], "金氏");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "");
+    assert_eq!(std::fs::read_to_string(out.join("chapter1.txt")).unwrap(), "학교");
+    assert_eq!(std::fs::read_to_string(out.join("stdin.txt")).unwrap(), "김씨");
+
+    // 다른 디렉토리의 같은 이름 파일은 서로 덮어쓰므로 아무것도 변환하지 않고 종료 코드 1
+    std::fs::create_dir_all(dir.join("b")).unwrap();
+    let other = dir.join("b").join("chapter1.txt");
+    std::fs::write(&other, "金氏").unwrap();
+    let output = run(&["-o", out.to_str().unwrap(), input.to_str().unwrap(), other.to_str().unwrap()], "");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("출력 파일 이름이 같습니다"));
+    assert_eq!(std::fs::read_to_string(out.join("chapter1.txt")).unwrap(), "학교");
+    std::fs::remove_dir_all(&dir).unwrap();
+}
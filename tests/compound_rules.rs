@@ -0,0 +1,129 @@
+// 복합어 읽기 규칙 (사이시옷, 받침 탈락, 활음조)
+// 규칙 단어는 글자마다의 읽기에 규칙을 적용해서 읽고, 불규칙 변환 한자사전의 단어(Word)와 구별되는 Rule 구간이 되는지 확인한다.
+
+use std::sync::Arc;
+
+use rust_web::compound::{CompoundRule, COMPOUND_WORDS};
+use rust_web::{ConvertOptions, Dictionary, SpanKind};
+
+fn dictionary() -> Arc<Dictionary> {
+    rust_web::load_arc_dictionary().unwrap()
+}
+
+// 단어 하나를 변환한 (종류, 읽기, 규칙)
+fn convert_word(dic: &Dictionary, word: &str, options: &ConvertOptions) -> (SpanKind, String, Option<CompoundRule>) {
+    let conversion = dic.convert(word, options);
+    assert_eq!(conversion.spans.len(), 1, "{}", word);
+    let span = &conversion.spans[0];
+    (span.kind, span.reading.clone(), span.rule)
+}
+
+fn assert_rule(dic: &Dictionary, word: &str, reading: &str, rule: CompoundRule) {
+    let options = ConvertOptions::default();
+    assert_eq!(convert_word(dic, word, &options), (SpanKind::Rule, reading.to_string(), Some(rule)), "{}", word);
+}
+
+#[test]
+fn saisiot() {
+    let dic = dictionary();
+    for (word, reading) in [("庫間", "곳간"), ("貰房", "셋방"), ("數字", "숫자"), ("車間", "찻간"), ("退間", "툇간"), ("回數", "횟수")] {
+        assert_rule(&dic, word, reading, CompoundRule::Saisiot);
+    }
+}
+
+#[test]
+fn drop_final() {
+    let dic = dictionary();
+    for (word, reading) in [("六月", "유월"), ("十月", "시월"), ("初八日", "초파일"), ("木瓜", "모과")] {
+        assert_rule(&dic, word, reading, CompoundRule::DropFinal);
+    }
+}
+
+#[test]
+fn euphony() {
+    let dic = dictionary();
+    for (word, reading) in [("困難", "곤란"), ("論難", "논란"), ("許諾", "허락"), ("受諾", "수락"), ("快諾", "쾌락")] {
+        assert_rule(&dic, word, reading, CompoundRule::Euphony);
+    }
+}
+
+// 두음법칙을 끄면 규칙도 두음법칙을 적용하지 않은 읽기에 적용한다.
+#[test]
+fn rule_without_dueum() {
+    let dic = dictionary();
+    let options = ConvertOptions { dueum: false, ..ConvertOptions::default() };
+    assert_eq!(convert_word(&dic, "六月", &options), (SpanKind::Rule, "류월".to_string(), Some(CompoundRule::DropFinal)));
+    assert_eq!(convert_word(&dic, "論難", &options), (SpanKind::Rule, "론란".to_string(), Some(CompoundRule::Euphony)));
+}
+
+#[test]
+fn dictionary_words_are_not_rules() {
+    let dic = dictionary();
+    let options = ConvertOptions::default();
+    assert_eq!(convert_word(&dic, "金氏", &options), (SpanKind::Word, "김씨".to_string(), None));
+}
+
+// 규칙 단어가 문장 안에 있어도 구간이 나뉘고, 출력에는 규칙이 적용된 읽기가 나온다.
+#[test]
+fn rule_in_sentence() {
+    let dic = dictionary();
+    let conversion = dic.convert("六月에 許諾했다", &ConvertOptions::default());
+    let rules: Vec<(&str, Option<CompoundRule>)> = conversion.spans.iter()
+        .filter(|span| span.kind == SpanKind::Rule)
+        .map(|span| (span.source.as_str(), span.rule))
+        .collect();
+    assert_eq!(rules, [("六月", Some(CompoundRule::DropFinal)), ("許諾", Some(CompoundRule::Euphony))]);
+    assert_eq!(conversion.render(ConvertOptions::default().mode), "유월에 허락했다");
+}
+
+// 규칙 단어는 불규칙 변환 한자사전에 따로 적지 않는다.
+#[test]
+fn rule_words_are_not_in_dictionary() {
+    let (_, _, word_dic) = rust_web::load_dictionary().unwrap();
+    for (word, _) in COMPOUND_WORDS {
+        assert!(!word_dic.contains_key(*word), "{}", word);
+    }
+}
+
+// 규칙 단어는 앞에 한글이나 다른 한자가 이어져 있어도 찾는다.
+// 이어진 한자 전체가 다르게 읽히는 단어는 사전에 적는다. (五六月 오뉴월, 十六月 십육월)
+#[test]
+fn rule_in_context() {
+    let dic = dictionary();
+    let options = ConvertOptions::default();
+    for (text, word, reading) in [
+        ("올해六月에", "六月", "유월"),
+        ("每年十月", "十月", "시월"),
+        ("이번數字", "數字", "숫자"),
+        ("總回數", "回數", "횟수"),
+        ("大困難", "困難", "곤란"),
+    ] {
+        let conversion = dic.convert(text, &options);
+        let rules = conversion.spans.iter()
+            .filter(|span| span.kind == SpanKind::Rule)
+            .map(|span| (span.source.as_str(), span.reading.as_str()))
+            .collect::<Vec<(&str, &str)>>();
+        assert_eq!(rules, [(word, reading)], "{}", text);
+    }
+    assert_eq!(dic.convert("올해六月에 總回數", &options).render(options.mode), "올해유월에 총횟수");
+    assert_eq!(dic.convert("올해 六月, 十月", &options).render(options.mode), "올해 유월, 시월");
+
+    assert_eq!(convert_word(&dic, "五六月", &options), (SpanKind::Word, "오뉴월".to_string(), None));
+    assert_eq!(convert_word(&dic, "十六月", &options), (SpanKind::Word, "십육월".to_string(), None));
+}
+
+// 규칙으로 옮기기 전에 불규칙 변환 한자사전에 적혀 있던 읽기와 같아야 한다. (論難, 許諾, 受諾, 快諾은 규칙과 함께 더한 단어)
+#[test]
+fn same_as_former_dictionary_readings() {
+    let dic = dictionary();
+    let options = ConvertOptions::default();
+    let readings = [
+        ("庫間", "곳간"), ("貰房", "셋방"), ("數字", "숫자"), ("車間", "찻간"), ("退間", "툇간"), ("回數", "횟수"),
+        ("六月", "유월"), ("十月", "시월"), ("初八日", "초파일"), ("木瓜", "모과"),
+        ("困難", "곤란"), ("論難", "논란"), ("許諾", "허락"), ("受諾", "수락"), ("快諾", "쾌락"),
+    ];
+    for (word, _) in COMPOUND_WORDS {
+        let reading = readings.iter().find(|(w, _)| w == word).map(|(_, r)| *r).unwrap_or_else(|| panic!("{}: 읽기가 없습니다", word));
+        assert_eq!(dic.convert(word, &options).render(options.mode), reading, "{}", word);
+    }
+}
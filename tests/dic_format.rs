@@ -0,0 +1,66 @@
+// 구조화 형식의 사전과 한자 정보 사전
+// 사전 한 줄의 두 가지 형식을 읽고, 한자 정보 사전에 있는 한자에만 훈, 부수, 획수, 급수가 있는지 확인한다.
+
+use rust_web::dic_format::{parse_line, Line, Record};
+use rust_web::{EduLevel, Reading};
+
+fn record(line: &str) -> Record {
+    match parse_line(line) {
+        Ok(Line::Record(record)) => record,
+        other => panic!("{:?}: {:?}", line, other),
+    }
+}
+
+#[test]
+fn pair_and_blank() {
+    assert_eq!(parse_line("女子, 여자"), Ok(Line::Pair("女子".to_string(), "여자".to_string())));
+    assert_eq!(parse_line("# 주석"), Ok(Line::Blank));
+    assert_eq!(parse_line("  "), Ok(Line::Blank));
+    assert!(parse_line("女子").is_err());
+    assert!(parse_line("女子,").is_err());
+    assert!(parse_line("女,子,여").is_err());
+}
+
+#[test]
+fn full_record() {
+    let r = record("樂\t악,락,요\t노래/즐길/좋아할\t木+11\t15\t6급II\t중학교");
+    assert_eq!(r.hanja, '樂');
+    assert_eq!(r.readings, [Reading { hangul: '악', rank: 1 }, Reading { hangul: '락', rank: 2 }, Reading { hangul: '요', rank: 3 }]);
+    assert_eq!(r.meaning, "노래/즐길/좋아할");
+    assert_eq!((r.radical, r.extra_strokes, r.strokes), (Some('木'), Some(11), Some(15)));
+    assert_eq!(r.grade.as_deref(), Some("6급II"));
+    assert_eq!(r.edu, EduLevel::Middle);
+}
+
+// 뒤쪽의 빈 필드는 생략할 수 있고, 순위를 직접 적으면 순위 순서로 정렬한다.
+#[test]
+fn partial_record() {
+    let r = record("樂\t악:2,락:1\t\t木");
+    assert_eq!(r.readings, [Reading { hangul: '락', rank: 1 }, Reading { hangul: '악', rank: 2 }]);
+    assert_eq!((r.radical, r.extra_strokes, r.strokes, r.grade), (Some('木'), None, None, None));
+    assert_eq!(r.edu, EduLevel::None);
+}
+
+#[test]
+fn bad_records() {
+    for line in ["樂\t", "樂樂\t악", "樂\t악락", "樂\t악\t\t木+x", "樂\t악\t\t\t열다섯", "樂\t악\t\t\t\t\t대학교", "樂\t악\t\t\t\t\t\t\t"] {
+        assert!(parse_line(line).is_err(), "{:?}", line);
+    }
+}
+
+// 한자 정보 사전에 있는 한자만 훈, 부수, 획수, 급수가 있다.
+#[test]
+fn info_coverage() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    assert_eq!(dic.info_dic.len(), 61);
+    for info in dic.info_dic.values() {
+        assert!(!info.meaning.is_empty() && info.radical.is_some() && info.strokes.is_some() && info.grade.is_some(), "{}", info.hanja);
+    }
+    assert_eq!(dic.info_dic.values().filter(|info| info.grade == Some("8급")).count(), 50);
+
+    let school = dic.info('校').unwrap();
+    assert_eq!((school.meaning, school.radical, school.strokes), ("학교", Some('木'), Some(10)));
+    // 기본한자 사전에만 있는 한자는 읽기만 있다.
+    assert_eq!(dic.char_dic.get(&'伽'), Some(&'가'));
+    assert!(dic.info('伽').is_none());
+}
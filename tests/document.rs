@@ -0,0 +1,129 @@
+// 문서 파일 변환 (EPUB, DOCX)
+// 메모리에서 만든 zip 파일로 본문 파일만 변환하고, 나머지 파일은 그대로 복사하는지 확인한다.
+// DOCX는 여러 run(<w:r>)으로 나뉜 단어, <w:delText>와 <w:ruby> 건너뛰기, 탭/줄바꿈에서 끊기, xml:space 보존을 확인한다.
+
+use std::io::{Cursor, Read, Write};
+
+use zip::write::SimpleFileOptions;
+use zip::{ZipArchive, ZipWriter};
+
+use rust_web::document::DocumentKind;
+use rust_web::{ConvertOptions, Dictionary, OutputMode};
+
+fn zip(files: &[(&str, &str)]) -> Vec<u8> {
+    let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+    for (name, content) in files {
+        writer.start_file(*name, SimpleFileOptions::default()).unwrap();
+        writer.write_all(content.as_bytes()).unwrap();
+    }
+    writer.finish().unwrap().into_inner()
+}
+
+fn unzip(bytes: &[u8]) -> Vec<(String, String)> {
+    let mut archive = ZipArchive::new(Cursor::new(bytes)).unwrap();
+    (0..archive.len())
+        .map(|i| {
+            let mut file = archive.by_index(i).unwrap();
+            let mut content = String::new();
+            file.read_to_string(&mut content).unwrap();
+            (file.name().to_string(), content)
+        })
+        .collect()
+}
+
+// 문단 하나짜리 word/document.xml을 변환한 결과의 <w:body> 안쪽
+fn convert_body(dic: &Dictionary, body: &str) -> String {
+    let xml = format!("<w:document><w:body>{}</w:body></w:document>", body);
+    let docx = zip(&[("word/document.xml", &xml)]);
+    let result = dic.convert_document(&docx, DocumentKind::Docx, &ConvertOptions::default()).unwrap();
+    let files = unzip(&result.bytes);
+    files[0].1.strip_prefix("<w:document><w:body>").unwrap().strip_suffix("</w:body></w:document>").unwrap().to_string()
+}
+
+// 한 단어가 서식이 다른 두 run으로 나뉘어 있으면 합쳐서 찾고, 단어가 시작하는 <w:t>에 넣는다.
+#[test]
+fn word_split_across_runs() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    assert_eq!(convert_body(&dic, "<w:p><w:r><w:t>金</w:t></w:r><w:r><w:rPr><w:b/></w:rPr><w:t>氏는</w:t></w:r></w:p>"),
+        "<w:p><w:r><w:t>김씨</w:t></w:r><w:r><w:rPr><w:b/></w:rPr><w:t>는</w:t></w:r></w:p>");
+}
+
+#[test]
+fn deleted_text_and_ruby() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    assert_eq!(convert_body(&dic, "<w:p><w:del><w:r><w:delText>學校</w:delText></w:r></w:del><w:r><w:t>學校</w:t></w:r></w:p>"),
+        "<w:p><w:del><w:r><w:delText>學校</w:delText></w:r></w:del><w:r><w:t>학교</w:t></w:r></w:p>");
+    let ruby = "<w:r><w:ruby><w:rt><w:r><w:t>학교</w:t></w:r></w:rt><w:rubyBase><w:r><w:t>學校</w:t></w:r></w:rubyBase></w:ruby></w:r>";
+    assert_eq!(convert_body(&dic, &format!("<w:p>{}<w:r><w:t>學校</w:t></w:r></w:p>", ruby)),
+        format!("<w:p>{}<w:r><w:t>학교</w:t></w:r></w:p>", ruby));
+}
+
+// 탭과 줄바꿈에서 텍스트를 끊으므로 양쪽의 글자를 한 단어로 보지 않는다. (數字는 숫자, 數와 字는 수, 자)
+#[test]
+fn breaks() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    assert_eq!(convert_body(&dic, "<w:p><w:r><w:t>數</w:t><w:t>字</w:t></w:r></w:p>"), "<w:p><w:r><w:t>숫자</w:t><w:t></w:t></w:r></w:p>");
+    for separator in ["<w:tab/>", "<w:br/>"] {
+        assert_eq!(convert_body(&dic, &format!("<w:p><w:r><w:t>數</w:t>{}<w:t>字</w:t></w:r></w:p>", separator)),
+            format!("<w:p><w:r><w:t>수</w:t>{}<w:t>자</w:t></w:r></w:p>", separator));
+    }
+    assert_eq!(convert_body(&dic, "<w:p><w:r><w:t>數</w:t></w:r></w:p><w:p><w:r><w:t>字</w:t></w:r></w:p>"),
+        "<w:p><w:r><w:t>수</w:t></w:r></w:p><w:p><w:r><w:t>자</w:t></w:r></w:p>");
+}
+
+// <w:t>의 속성과 앞뒤 공백은 그대로 둔다.
+#[test]
+fn xml_space() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    assert_eq!(convert_body(&dic, r#"<w:p><w:r><w:t xml:space="preserve"> 金</w:t></w:r><w:r><w:t xml:space="preserve">氏 </w:t></w:r></w:p>"#),
+        r#"<w:p><w:r><w:t xml:space="preserve"> 김씨</w:t></w:r><w:r><w:t xml:space="preserve"> </w:t></w:r></w:p>"#);
+}
+
+// 본문이 아닌 파일은 그대로, 같은 순서로 복사한다.
+#[test]
+fn docx_parts() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    let docx = zip(&[
+        ("[Content_Types].xml", "<Types/>"),
+        ("word/document.xml", "<w:p><w:r><w:t>學校</w:t></w:r></w:p>"),
+        ("word/styles.xml", "<w:t>學校</w:t>"),
+        ("word/header1.xml", "<w:p><w:r><w:t>學校</w:t></w:r></w:p>"),
+    ]);
+    let result = dic.convert_document(&docx, DocumentKind::Docx, &ConvertOptions::default()).unwrap();
+    assert!(result.converted);
+    assert_eq!(result.parts, 2);
+    assert_eq!(unzip(&result.bytes), [
+        ("[Content_Types].xml".to_string(), "<Types/>".to_string()),
+        ("word/document.xml".to_string(), "<w:p><w:r><w:t>학교</w:t></w:r></w:p>".to_string()),
+        ("word/styles.xml".to_string(), "<w:t>學校</w:t>".to_string()),
+        ("word/header1.xml".to_string(), "<w:p><w:r><w:t>학교</w:t></w:r></w:p>".to_string()),
+    ]);
+
+    let options = ConvertOptions { mode: OutputMode::Ruby, ..ConvertOptions::default() };
+    assert!(dic.convert_document(&docx, DocumentKind::Docx, &options).is_err());
+}
+
+#[test]
+fn epub() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    let epub = zip(&[
+        ("mimetype", "application/epub+zip"),
+        ("OEBPS/content.opf", "<dc:title>學校</dc:title>"),
+        ("OEBPS/chapter1.xhtml", "<html><head><title>學校</title></head><body><p>金氏</p><code>學校</code></body></html>"),
+    ]);
+    let options = ConvertOptions { mode: OutputMode::Ruby, ..ConvertOptions::default() };
+    let result = dic.convert_document(&epub, DocumentKind::Epub, &options).unwrap();
+    assert_eq!(result.parts, 1);
+    let files = unzip(&result.bytes);
+    assert_eq!(files[0], ("mimetype".to_string(), "application/epub+zip".to_string()));
+    assert_eq!(files[1].1, "<dc:title>學校</dc:title>");
+    assert_eq!(files[2].1, "<html><head><title>學校</title></head><body><p><ruby>金氏<rt>김씨</rt></ruby></p><code>學校</code></body></html>");
+}
+
+#[test]
+fn not_a_zip() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    assert!(dic.convert_document(b"not a zip", DocumentKind::Epub, &ConvertOptions::default()).is_err());
+    assert_eq!(DocumentKind::from_file_name("책.EPUB"), Some(DocumentKind::Epub));
+    assert_eq!(DocumentKind::from_file_name("report.doc"), None);
+}
@@ -0,0 +1,29 @@
+// ETag와 If-None-Match 비교
+// 응답은 압축 방식에 따라 byte가 달라지므로 ETag는 약한 태그이고, If-None-Match는 약한 비교를 사용한다.
+
+use rust_web::assets::Assets;
+use rust_web::{if_none_match, weak_etag};
+
+#[test]
+fn weak_tag() {
+    assert_eq!(weak_etag("abc"), "W/\"abc\"");
+}
+
+#[test]
+fn weak_comparison() {
+    let etag = weak_etag("abc");
+    assert!(if_none_match("W/\"abc\"", &etag));
+    assert!(if_none_match("\"abc\"", &etag));
+    assert!(if_none_match("\"x\", W/\"abc\"", &etag));
+    assert!(if_none_match("*", &etag));
+    assert!(!if_none_match("W/\"abd\"", &etag));
+    assert!(!if_none_match("", &etag));
+}
+
+#[tokio::test]
+async fn asset_tags_are_weak() {
+    let assets = Assets::new(false);
+    let index = assets.get("index.html").await.unwrap();
+    assert!(index.etag.starts_with("W/\""), "{}", index.etag);
+    assert_ne!(index.etag, assets.get("js/main.js").await.unwrap().etag);
+}
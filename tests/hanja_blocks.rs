@@ -0,0 +1,161 @@
+// 유니코드 블록별 한자 인식과 변환
+// 각 블록의 첫 글자와 마지막 글자를 한자로 인식하는지, 사전에 읽기가 있으면 변환하고 없으면 Unknown으로 남기는지 확인한다.
+
+use std::sync::Arc;
+
+use rust_web::{is_chi, ConvertOptions, Dictionary, SpanKind};
+
+fn dictionary() -> Arc<Dictionary> {
+    rust_web::load_arc_dictionary().unwrap()
+}
+
+// 두음법칙 없이 한 글자를 변환한 (종류, 읽기)
+fn convert_char(dic: &Dictionary, c: char) -> (SpanKind, String) {
+    let options = ConvertOptions { dueum: false, ..ConvertOptions::default() };
+    let conversion = dic.convert(&c.to_string(), &options);
+    assert_eq!(conversion.spans.len(), 1, "{:?}", c);
+    let span = &conversion.spans[0];
+    (span.kind, span.reading.clone())
+}
+
+fn assert_block(first: u32, last: u32) {
+    for n in [first, last] {
+        let c = char::from_u32(n).unwrap();
+        assert!(is_chi(&c), "U+{:04X}", n);
+    }
+}
+
+fn assert_reading(dic: &Dictionary, c: char, reading: &str) {
+    assert_eq!(convert_char(dic, c), (SpanKind::Char, reading.to_string()), "U+{:04X}", c as u32);
+}
+
+fn assert_unknown(dic: &Dictionary, c: char) {
+    assert_eq!(convert_char(dic, c), (SpanKind::Unknown, c.to_string()), "U+{:04X}", c as u32);
+}
+
+#[test]
+fn cjk_radicals_supplement() {
+    let dic = dictionary();
+    assert_block(0x2E80, 0x2EF3);
+    assert!(!is_chi(&'\u{2E9A}'));  // 배정되지 않은 코드 포인트
+    assert_reading(&dic, '\u{2E9F}', "모");  // ⺟ -> 母
+    assert_unknown(&dic, '\u{2E80}');
+}
+
+#[test]
+fn kangxi_radicals() {
+    let dic = dictionary();
+    assert_block(0x2F00, 0x2FD5);
+    assert!(!is_chi(&'\u{2FD6}'));
+    assert_reading(&dic, '\u{2F00}', "일");  // ⼀ -> 一
+    assert_reading(&dic, '\u{2F54}', "수");  // ⽔ -> 水
+}
+
+#[test]
+fn extension_a() {
+    let dic = dictionary();
+    assert_block(0x3400, 0x4DBF);
+    assert_reading(&dic, '\u{3400}', "구");
+}
+
+#[test]
+fn unified_ideographs() {
+    let dic = dictionary();
+    assert_block(0x4E00, 0x9FFF);
+    assert_reading(&dic, '一', "일");
+    assert_reading(&dic, '國', "국");
+}
+
+#[test]
+fn compatibility_ideographs() {
+    let dic = dictionary();
+    assert_block(0xF900, 0xFAD9);
+    assert!(!is_chi(&'\u{FA6E}'));
+    assert!(!is_chi(&'\u{FADA}'));
+    assert_reading(&dic, '\u{F900}', "개");  // 사전에 따로 있는 읽기
+    assert_reading(&dic, '\u{F914}', "낙");
+    assert_reading(&dic, '\u{FA70}', "병");  // 사전에 없으면 통합 한자(並)의 읽기
+}
+
+#[test]
+fn compatibility_ideographs_supplement() {
+    let dic = dictionary();
+    assert_block(0x2F800, 0x2FA1D);
+    assert!(!is_chi(&'\u{2FA1E}'));
+    assert_reading(&dic, '\u{2F800}', "려");  // 丽 -> 丽
+}
+
+#[test]
+fn extension_b() {
+    let dic = dictionary();
+    assert_block(0x20000, 0x2A6DF);
+    assert!(!is_chi(&'\u{2A6E0}'));
+    assert_unknown(&dic, '\u{20000}');
+}
+
+#[test]
+fn extension_c() {
+    assert_block(0x2A700, 0x2B739);
+    assert_unknown(&dictionary(), '\u{2A700}');
+}
+
+#[test]
+fn extension_d() {
+    assert_block(0x2B740, 0x2B81D);
+    assert_unknown(&dictionary(), '\u{2B740}');
+}
+
+#[test]
+fn extension_e() {
+    assert_block(0x2B820, 0x2CEA1);
+    assert_unknown(&dictionary(), '\u{2B820}');
+}
+
+#[test]
+fn extension_f() {
+    assert_block(0x2CEB0, 0x2EBE0);
+    assert_unknown(&dictionary(), '\u{2CEB0}');
+}
+
+#[test]
+fn extension_g() {
+    assert_block(0x30000, 0x3134A);
+    assert_unknown(&dictionary(), '\u{30000}');
+}
+
+#[test]
+fn extension_h() {
+    assert_block(0x31350, 0x323AF);
+    assert_unknown(&dictionary(), '\u{31350}');
+}
+
+#[test]
+fn extension_i() {
+    assert_block(0x2EBF0, 0x2EE5D);
+    assert_unknown(&dictionary(), '\u{2EBF0}');
+}
+
+#[test]
+fn not_hanja() {
+    for c in ['A', '가', '〇', '々', '\u{2FF0}', '\u{31C0}', '\u{3300}'] {
+        assert!(!is_chi(&c), "U+{:04X}", c as u32);
+    }
+}
+
+// 4바이트 글자의 위치(byte)가 맞고, 사전에 없는 한자로 보고되는지
+#[test]
+fn supplementary_plane_spans() {
+    let dic = dictionary();
+    let conversion = dic.convert("가\u{20000}나", &ConvertOptions::default());
+    let spans = conversion.spans.iter().map(|span| (span.start, span.end, span.kind)).collect::<Vec<_>>();
+    assert_eq!(spans, vec![(0, 3, SpanKind::Text), (3, 7, SpanKind::Unknown), (7, 10, SpanKind::Text)]);
+    assert_eq!(conversion.unknown(), vec!['\u{20000}']);
+}
+
+// 확장 한자 뒤에 이어진 불규칙 변환 단어도 찾는다.
+#[test]
+fn word_after_extension_char() {
+    let dic = dictionary();
+    let conversion = dic.convert("\u{20000}金氏", &ConvertOptions::default());
+    assert_eq!(conversion.render(rust_web::OutputMode::Hangul), "\u{20000}김씨");
+}
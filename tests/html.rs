@@ -0,0 +1,68 @@
+// HTML 문서 변환 (format: html)
+// 텍스트 노드만 변환하고, 마크업과 건너뛰는 요소(translate="no", data-no-hanja, <script> 등)는 그대로 두는지 확인한다.
+
+use rust_web::html::HtmlOptions;
+use rust_web::{ConvertOptions, Dictionary, OutputMode};
+
+fn convert(dic: &Dictionary, html: &str, mode: OutputMode) -> String {
+    let options = ConvertOptions { mode, ..ConvertOptions::default() };
+    dic.convert_html(html, &options, &HtmlOptions::default()).render(mode)
+}
+
+#[test]
+fn text_nodes_only() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    let html = r#"<p title="大韓民國">大韓<b>民國</b></p><!-- 國民 -->"#;
+    assert_eq!(convert(&dic, html, OutputMode::Hangul), r#"<p title="大韓民國">대한<b>민국</b></p><!-- 國民 -->"#);
+}
+
+#[test]
+fn translate_no() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    assert_eq!(convert(&dic, r#"<p translate="no">學校</p><p>學校</p>"#, OutputMode::Hangul), r#"<p translate="no">學校</p><p>학교</p>"#);
+    // 안쪽 요소에서 translate="yes"로 다시 켤 수 있다.
+    assert_eq!(convert(&dic, r#"<div translate="no">學校<span translate="yes">學校</span></div>"#, OutputMode::Hangul),
+        r#"<div translate="no">學校<span translate="yes">학교</span></div>"#);
+}
+
+// data-no-hanja 안쪽은 다시 켤 수 없다.
+#[test]
+fn data_no_hanja() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    let html = r#"<div data-no-hanja>學校<span translate="yes" lang="ko">學校</span></div>學校"#;
+    assert_eq!(convert(&dic, html, OutputMode::Hangul), r#"<div data-no-hanja>學校<span translate="yes" lang="ko">學校</span></div>학교"#);
+}
+
+#[test]
+fn script_and_style() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    let html = "<script>let s = \"<b>學校</b>\";</script><style>p::after { content: \"學校\" }</style><p>學校</p>";
+    assert_eq!(convert(&dic, html, OutputMode::Hangul),
+        "<script>let s = \"<b>學校</b>\";</script><style>p::after { content: \"學校\" }</style><p>학교</p>");
+}
+
+#[test]
+fn skip_elements() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    assert_eq!(convert(&dic, "<code>學校</code> <pre>學校</pre> 學校", OutputMode::Hangul), "<code>學校</code> <pre>學校</pre> 학교");
+
+    let html = HtmlOptions { skip_elements: vec!["em".to_string()] };
+    let conversion = dic.convert_html("<em>學校</em><code>學校</code>", &ConvertOptions::default(), &html);
+    assert_eq!(conversion.render(OutputMode::Hangul), "<em>學校</em><code>학교</code>");
+}
+
+#[test]
+fn foreign_lang() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    let html = r#"<p lang="zh">學校<span lang="ko-KR">學校</span></p>"#;
+    assert_eq!(convert(&dic, html, OutputMode::Hangul), r#"<p lang="zh">學校<span lang="ko-KR">학교</span></p>"#);
+}
+
+// <title> 안에는 <ruby>를 넣을 수 없으므로 ruby 형식이면 변환하지 않는다.
+#[test]
+fn ruby_in_title() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    let html = "<title>學校</title><p>學校</p>";
+    assert_eq!(convert(&dic, html, OutputMode::Hangul), "<title>학교</title><p>학교</p>");
+    assert_eq!(convert(&dic, html, OutputMode::Ruby), "<title>學校</title><p><ruby>學<rt>학</rt></ruby><ruby>校<rt>교</rt></ruby></p>");
+}
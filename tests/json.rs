@@ -0,0 +1,66 @@
+// JSON 문서 변환 (format: json)
+// JSONPath와 JSON Pointer로 선택한 문자열만 변환하고, 여러 선택자가 같은 문자열을 선택해도 한 번만 변환하는지 확인한다.
+
+use serde_json::{json, Value};
+
+use rust_web::json::JsonConversion;
+use rust_web::{ConvertOptions, Dictionary, OutputMode};
+
+fn convert(dic: &Dictionary, document: Value, paths: &[&str]) -> Result<JsonConversion, String> {
+    let options = ConvertOptions { mode: OutputMode::HanjaHangul, ..ConvertOptions::default() };
+    dic.convert_json(document, &paths.iter().map(|p| p.to_string()).collect::<Vec<String>>(), &options)
+}
+
+fn document() -> Value {
+    json!({"items": [{"name": "金氏", "memo": "學校"}, {"name": "國民", "memo": "大韓"}], "學校": "學校"})
+}
+
+#[test]
+fn all_strings() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    let result = convert(&dic, document(), &[]).unwrap();
+    // 객체의 key는 변환하지 않는다.
+    assert_eq!(result.document, json!({
+        "items": [{"name": "金氏(김씨)", "memo": "學校(학교)"}, {"name": "國民(국민)", "memo": "大韓(대한)"}],
+        "學校": "學校(학교)",
+    }));
+}
+
+#[test]
+fn json_path_and_pointer() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    let result = convert(&dic, document(), &["$.items[*].name", "/items/1/memo"]).unwrap();
+    assert_eq!(result.document["items"], json!([{"name": "金氏(김씨)", "memo": "學校"}, {"name": "國民(국민)", "memo": "大韓(대한)"}]));
+    assert_eq!(result.document["學校"], "學校");
+    let stats = result.paths.iter().map(|s| (s.path.as_str(), s.matched, s.strings, s.converted)).collect::<Vec<_>>();
+    assert_eq!(stats, [("$.items[*].name", 2, 2, 2), ("/items/1/memo", 1, 1, 1)]);
+}
+
+// 이미 변환한 문자열은 다시 변환하지 않는다. ("金氏(김씨)(김씨)"가 되지 않는다.)
+#[test]
+fn selected_twice() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    let result = convert(&dic, document(), &["$.items[0].name", "/items/0/name", "/items/0", "$..name"]).unwrap();
+    assert_eq!(result.document["items"][0], json!({"name": "金氏(김씨)", "memo": "學校(학교)"}));
+    assert_eq!(result.document["items"][1]["name"], "國民(국민)");
+    let strings = result.paths.iter().map(|s| s.strings).collect::<Vec<_>>();
+    assert_eq!(strings, [1, 0, 1, 1]);
+}
+
+// '/'와 '~'가 들어 있는 key도 JSONPath와 JSON Pointer가 같은 문자열로 본다.
+#[test]
+fn escaped_keys() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    let result = convert(&dic, json!({"a/b": "學校", "c~d": ["金氏"]}), &["$['a/b']", "/a~1b", "/c~0d", "$['c~d'][0]"]).unwrap();
+    assert_eq!(result.document, json!({"a/b": "學校(학교)", "c~d": ["金氏(김씨)"]}));
+}
+
+#[test]
+fn bad_selector() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    assert!(convert(&dic, document(), &["items.name"]).is_err());
+    assert!(convert(&dic, document(), &["$.items[", "/items"]).is_err());
+    // 없는 위치는 선택한 값이 없을 뿐이다.
+    let result = convert(&dic, document(), &["/missing"]).unwrap();
+    assert_eq!((result.paths[0].matched, result.converted), (0, false));
+}
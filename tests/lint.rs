@@ -0,0 +1,42 @@
+// 사전 검사 도구
+// 잘못된 줄을 찾아내는지, 보고하는 줄 번호가 사전 원본 파일의 줄을 가리키는지 확인한다.
+
+use rust_web::lint::{literal_first_line, validate_dictionaries, validate_word_dic, Severity};
+
+const CHARS: &str = "金,금\n氏,씨\n車,거\n庫,고\n";
+const DUEUM: &str = "녀,여\n";
+
+#[test]
+fn bad_entries() {
+    let text = "金氏,김씨\n車庫\n車庫,차고\n車庫,거고\n金X,김엑\n";
+    let issues = validate_word_dic("WORDS", text, CHARS, DUEUM);
+    let found = issues.iter().map(|issue| (issue.line, issue.severity)).collect::<Vec<_>>();
+    assert!(found.contains(&(2, Severity::Error)), "{:?}", found);  // 콤마가 없는 줄
+    assert!(found.contains(&(4, Severity::Error)), "{:?}", found);  // 3번째 줄과 값이 다른 중복
+    assert!(found.contains(&(5, Severity::Error)), "{:?}", found);  // 한자가 아닌 key
+    assert!(!found.iter().any(|(line, _)| *line == 1 || *line == 3), "{:?}", found);
+    assert_eq!(issues[0].to_string().split(':').next(), Some("WORDS"));
+}
+
+#[test]
+fn literal_start() {
+    let source = "// 주석\npub const WORDS:&str = \nr#\"金氏,김씨\n車庫\n\"#;\n";
+    assert_eq!(literal_first_line(source, "WORDS"), Some(3));
+    assert_eq!(literal_first_line(source, "OTHER"), None);
+}
+
+// 사전의 줄 번호는 원본 파일의 줄 번호이다. 보고된 줄에 보고된 단어가 있어야 한다.
+#[test]
+fn file_line_numbers() {
+    let word_source = include_str!("../src/hanja_word.rs");
+    assert_eq!(literal_first_line(word_source, "HANJA_SPECIAL"), Some(3));
+    assert!(word_source.lines().nth(2).unwrap().ends_with("客車,객차"));
+
+    let issues = validate_dictionaries();
+    let word_issue = issues.iter().find(|issue| issue.dictionary == "HANJA_SPECIAL").expect("HANJA_SPECIAL 경고");
+    assert_eq!(word_issue.file, Some("src/hanja_word.rs"));
+    let line = word_source.lines().nth(word_issue.line - 1).unwrap();
+    let entry = word_issue.message.split(':').next().unwrap();
+    assert!(line.ends_with(entry), "{}", word_issue);  // 첫 줄은 r#"로 시작한다.
+    assert!(word_issue.to_string().starts_with(&format!("src/hanja_word.rs:{}:", word_issue.line)));
+}
@@ -0,0 +1,47 @@
+// Markdown 문서 변환 (format: markdown)
+// 본문 글자만 변환하고, 코드와 본문 안의 인라인 HTML(<code> 등)은 그대로 두는지 확인한다.
+
+use rust_web::{ConvertOptions, Dictionary, OutputMode};
+
+fn convert(dic: &Dictionary, markdown: &str) -> String {
+    dic.convert_markdown(markdown, &ConvertOptions::default()).render(OutputMode::Hangul)
+}
+
+#[test]
+fn code_spans_and_blocks() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    assert_eq!(convert(&dic, "學校 `學校` 學校"), "학교 `學校` 학교");
+    assert_eq!(convert(&dic, "# 學校\n\n```\n學校\n```\n\n    學校\n"), "# 학교\n\n```\n學校\n```\n\n    學校\n");
+}
+
+// 본문 안에 HTML로 쓴 <code>, <kbd> 등은 HTML 형식과 같이 건너뛴다.
+#[test]
+fn inline_html() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    assert_eq!(convert(&dic, "學校 <code>學校</code> <kbd>金氏</kbd> <b>學校</b>"), "학교 <code>學校</code> <kbd>金氏</kbd> <b>학교</b>");
+    assert_eq!(convert(&dic, r#"<span translate="no">學校 *學校*</span> 學校"#), r#"<span translate="no">學校 *學校*</span> 학교"#);
+    // 닫는 태그가 없으면 문단이 끝날 때까지 건너뛴다.
+    assert_eq!(convert(&dic, "<code>學校\n\n學校"), "<code>學校\n\n학교");
+}
+
+#[test]
+fn html_block_and_front_matter() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    assert_eq!(convert(&dic, "---\ntitle: 學校\n---\n\n學校\n"), "---\ntitle: 學校\n---\n\n학교\n");
+    assert_eq!(convert(&dic, "<div>\n學校\n</div>\n\n學校"), "<div>\n學校\n</div>\n\n학교");
+}
+
+// 링크 주소는 그대로 두고 링크 텍스트만 변환한다.
+#[test]
+fn links() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    assert_eq!(convert(&dic, "[學校](https://example.com/學校) <https://example.com/學校>"),
+        "[학교](https://example.com/學校) <https://example.com/學校>");
+}
+
+// 이스케이프로 나뉜 텍스트도 이어서 단어를 찾는다.
+#[test]
+fn escaped_text() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    assert_eq!(convert(&dic, "\\*金氏\\*"), "\\*김씨\\*");
+}
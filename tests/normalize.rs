@@ -0,0 +1,50 @@
+// 호환용 한자와 이체자 정규화 (normalize: off, match, rewrite)
+// 정규화한 글자로 불규칙 변환 단어를 찾는지, rewrite일 때만 출력하는 한자를 바꾸는지 확인한다.
+
+use rust_web::{ConvertOptions, Dictionary, MappedChar, Normalize, OutputMode, SpanKind};
+
+fn convert(dic: &Dictionary, text: &str, normalize: Normalize) -> String {
+    let options = ConvertOptions { normalize, ..ConvertOptions::default() };
+    dic.convert(text, &options).render(OutputMode::HanjaHangul)
+}
+
+// 尚은 尙의 이체자, 尙父(상보)는 불규칙 변환 단어
+#[test]
+fn variant() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    assert_eq!(convert(&dic, "尚父", Normalize::Off), "尚父(상부)");
+    assert_eq!(convert(&dic, "尚父", Normalize::Match), "尚父(상보)");
+    assert_eq!(convert(&dic, "尚父", Normalize::Rewrite), "尙父(상보)");
+    assert_eq!(convert(&dic, "峰巒", Normalize::Rewrite), "峯巒(봉만)");
+}
+
+// 金 U+F90A는 호환용 한자, 金氏(김씨)는 통합 한자로 사전에 있다.
+#[test]
+fn compatibility_ideograph() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    assert_eq!(convert(&dic, "\u{F90A}氏", Normalize::Off), "\u{F90A}氏(금씨)");
+    assert_eq!(convert(&dic, "\u{F90A}氏", Normalize::Match), "\u{F90A}氏(김씨)");
+    assert_eq!(convert(&dic, "\u{F90A}氏", Normalize::Rewrite), "金氏(김씨)");
+}
+
+// 한 글자의 읽기는 입력한 글자의 읽기를 사용한다. (樂 U+F914는 낙)
+#[test]
+fn char_reading_is_kept() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    let options = ConvertOptions { normalize: Normalize::Rewrite, dueum: false, ..ConvertOptions::default() };
+    let conversion = dic.convert("\u{F914}", &options);
+    assert_eq!(conversion.render(OutputMode::HanjaHangul), "樂(낙)");
+    assert_eq!(conversion.spans[0].kind, SpanKind::Char);
+}
+
+// 바뀐 글자는 한 번씩 입력 순서대로 알려 준다.
+#[test]
+fn mapped() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    let text = "\u{F90A}氏 峰巒 \u{F90A}氏 尚父";
+    let options = ConvertOptions { normalize: Normalize::Match, ..ConvertOptions::default() };
+    let mapped = dic.convert(text, &options).mapped();
+    assert_eq!(mapped, [MappedChar { from: '\u{F90A}', to: '金' }, MappedChar { from: '峰', to: '峯' }, MappedChar { from: '尚', to: '尙' }]);
+
+    assert!(dic.convert(text, &ConvertOptions::default()).mapped().is_empty());
+}
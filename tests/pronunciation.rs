@@ -0,0 +1,73 @@
+// 표준 발음 (표준 발음법)
+// 소리의 변화마다 발음을 확인하고, 된소리되기와 거센소리되기를 적지 않는 로마자 표기와 비교한다.
+
+use rust_web::pronunciation::pronounce;
+use rust_web::romanize::{romanize, Romanization};
+use rust_web::{ConvertOptions, OutputMode};
+
+fn check(cases: &[(&str, &str)]) {
+    for (text, expected) in cases {
+        assert_eq!(pronounce(text), *expected, "{}", text);
+    }
+}
+
+// 제18, 19항
+#[test]
+fn nasalisation() {
+    check(&[("국민", "궁민"), ("독립", "동닙"), ("종로", "종노"), ("밥물", "밤물"), ("닫는", "단는")]);
+}
+
+// 제20항
+#[test]
+fn lateralisation() {
+    check(&[("신라", "실라"), ("칼날", "칼랄"), ("난로", "날로")]);
+}
+
+// 제23항, 한자어의 'ㄹ' 받침 뒤(제26항)
+#[test]
+fn tensification() {
+    check(&[("학교", "학꾜"), ("국밥", "국빱"), ("입고", "입꼬")]);
+    // 제26항은 한자의 읽기에만 적용한다.
+    assert_eq!(pronounce("발전"), "발전");
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    let pronunciations = dic.convert("發展", &ConvertOptions::default()).pronunciations();
+    assert_eq!(pronunciations[0].pronunciation, "발쩐");
+}
+
+// 제12항
+#[test]
+fn aspiration() {
+    check(&[("좋고", "조코"), ("백화점", "배콰점"), ("놓다", "노타"), ("좋아", "조아")]);
+}
+
+// 제13, 14항
+#[test]
+fn linking() {
+    check(&[("음악", "으막"), ("옷이", "오시"), ("값이", "갑씨"), ("닭을", "달글")]);
+}
+
+// 제17항
+#[test]
+fn palatalisation() {
+    check(&[("같이", "가치"), ("굳이", "구지"), ("해돋이", "해도지")]);
+}
+
+// 로마자 표기는 같은 규칙을 쓰지만 된소리되기와 받침 'ㄱ, ㄷ, ㅂ, ㅈ' 뒤의 거센소리되기는 적지 않는다.
+#[test]
+fn romanization_leaves_out_tensification_and_aspiration() {
+    assert_eq!(romanize("학교", &[], Romanization::Rr), "hakgyo");
+    assert_eq!(romanize("백화점", &[], Romanization::Rr), "baekhwajeom");
+    // 비음화, 유음화, 구개음화, 'ㅎ' 뒤의 거센소리되기는 적는다.
+    assert_eq!(romanize("국민 신라 같이 좋고", &[], Romanization::Rr), "gungmin silla gachi joko");
+}
+
+// 한자 단어마다 철자와 발음. 사전에서 찾은 단어는 앞의 한자와 나눈다.
+#[test]
+fn conversion() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    let conversion = dic.convert("獨立 新羅, 國民이 學校", &ConvertOptions::default());
+    let words = conversion.pronunciations().into_iter().map(|p| (p.source, p.reading, p.pronunciation)).collect::<Vec<_>>();
+    let expected = [("獨立", "독립", "동닙"), ("新羅", "신라", "실라"), ("國民", "국민", "궁민"), ("學校", "학교", "학꾜")];
+    assert_eq!(words, expected.map(|(s, r, p)| (s.to_string(), r.to_string(), p.to_string())));
+    assert_eq!(dic.convert("新羅 學校", &ConvertOptions::default()).render_pronounced(OutputMode::HanjaHangul), "新羅(신라)[실라] 學校(학교)[학꾜]");
+}
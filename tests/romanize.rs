@@ -0,0 +1,79 @@
+// 로마자 표기 (rr: 국어의 로마자 표기법, mr: 매큔-라이샤워 표기법)
+// 단어 안의 소리의 변화, 두 표기법의 차이, 단어 경계(breaks)를 확인한다.
+
+use rust_web::romanize::{romanize, Romanization};
+use rust_web::ConvertOptions;
+
+fn rr(text: &str) -> String {
+    romanize(text, &[], Romanization::Rr)
+}
+
+fn mr(text: &str) -> String {
+    romanize(text, &[], Romanization::Mr)
+}
+
+// 비음화, 유음화, 연음을 적고 된소리되기는 적지 않는다.
+#[test]
+fn sound_changes() {
+    for (text, expected_rr, expected_mr) in [
+        ("독립문", "dongnimmun", "tongnimmun"),
+        ("신라", "silla", "silla"),
+        ("학교", "hakgyo", "hakkyo"),
+        ("한강", "hangang", "han'gang"),
+        ("종로", "jongno", "chongno"),
+        ("설악", "seorak", "sŏrak"),
+        ("백화점", "baekhwajeom", "paekhwajŏm"),
+    ] {
+        assert_eq!(rr(text), expected_rr, "{}", text);
+        assert_eq!(mr(text), expected_mr, "{}", text);
+    }
+}
+
+// rr의 'ㄹㄹ'은 ll로 적는다.
+#[test]
+fn double_rieul() {
+    assert_eq!(rr("울릉"), "ulleung");
+    assert_eq!(rr("별래"), "byeollae");
+    assert_eq!(rr("라디오"), "radio");
+}
+
+// mr은 예사소리를 모음과 ㄴ, ㄹ, ㅁ, ㅇ 받침 뒤에서만 유성음으로 적고, ㄴ 받침 뒤의 ㄱ은 n'g로 적는다.
+#[test]
+fn mr_voicing() {
+    assert_eq!(mr("부산"), "pusan");
+    assert_eq!(mr("대구"), "taegu");
+    assert_eq!(mr("광화문"), "kwanghwamun");
+    assert_eq!(mr("한국"), "han'guk");
+    assert_eq!(mr("창경궁"), "ch'anggyŏnggung");
+    assert_eq!(mr("어머니"), "ŏmŏni");
+}
+
+// 한글이 아닌 글자는 그대로 두고, 단어를 끊는다.
+#[test]
+fn non_hangul() {
+    assert_eq!(rr("한국, 서울!"), "hanguk, seoul!");
+    assert_eq!(rr("学 학"), "学 hak");
+}
+
+// breaks의 위치에서는 앞 단어의 받침을 뒤로 옮기지 않는다.
+#[test]
+fn breaks() {
+    assert_eq!(romanize("북월지", &[], Romanization::Rr), "bugwolji");
+    assert_eq!(romanize("북월지", &["북".len()], Romanization::Rr), "bukwolji");
+    assert_eq!(romanize("신객차", &["신".len()], Romanization::Mr), "sinkaekch'a");
+    assert_eq!(romanize("신객차", &[], Romanization::Mr), "sin'gaekch'a");
+}
+
+// 사전에서 찾은 단어와 그 앞의 한자 사이는 단어 경계이다. 이어진 한자 가운데에서도 끊는다.
+#[test]
+fn conversion_word_boundaries() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    let options = ConvertOptions::default();
+    // 月氏(월지)는 사전 단어, 北月은 한 글자씩 변환한 한자
+    assert_eq!(dic.convert("北月氏", &options).romanize(Romanization::Rr), "bukwolji");
+    assert_eq!(dic.convert("北月", &options).romanize(Romanization::Rr), "bugwol");
+    assert_eq!(dic.convert("新客車", &options).romanize(Romanization::Mr), "sinkaekch'a");
+    // 한자와 이어진 한글(조사)은 한 단어로 본다.
+    assert_eq!(dic.convert("學校에", &options).romanize(Romanization::Rr), "hakgyoe");
+    assert_eq!(dic.convert("金氏 學校", &options).romanize(Romanization::Rr), "gimssi hakgyo");
+}
@@ -0,0 +1,53 @@
+// 읽기, 부수, 획수, 급수로 한자 찾기 (GET /api/search)
+// README에 적은 예시 검색이 결과를 돌려주는지, 조건을 함께 지정했을 때와 페이지 나누기를 확인한다.
+
+use rust_web::search::SearchQuery;
+
+fn chars(page: &rust_web::search::SearchPage) -> String {
+    page.results.iter().map(|hit| hit.hanja).collect()
+}
+
+// README의 예시: reading=가&page=2, radical=木&extra_strokes=4, grade=8급&strokes=4
+#[test]
+fn documented_queries() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+
+    let page = dic.search(&SearchQuery { reading: Some("가".to_string()), page: Some(2), ..SearchQuery::default() }).unwrap();
+    assert!(page.total > 50 && !page.results.is_empty(), "{:?}", page);
+    assert!(page.results.iter().all(|hit| hit.readings.contains(&'가')));
+
+    let page = dic.search(&SearchQuery { radical: Some("木".to_string()), extra_strokes: Some(4), ..SearchQuery::default() }).unwrap();
+    assert_eq!(chars(&page), "東");
+
+    let page = dic.search(&SearchQuery { grade: Some("8급".to_string()), strokes: Some(4), ..SearchQuery::default() }).unwrap();
+    assert!(page.total > 0);
+    assert!(chars(&page).contains('木') && chars(&page).contains('六'), "{}", chars(&page));
+    assert!(page.results.iter().all(|hit| hit.grade == Some("8급") && hit.strokes == Some(4)));
+}
+
+// 읽기가 여럿인 한자는 어느 읽기로도 찾을 수 있다.
+#[test]
+fn by_any_reading() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    for reading in ["악", "락", "요"] {
+        let page = dic.search(&SearchQuery { reading: Some(reading.to_string()), radical: Some("木".to_string()), ..SearchQuery::default() }).unwrap();
+        assert!(chars(&page).contains('樂'), "{}: {}", reading, chars(&page));
+    }
+}
+
+#[test]
+fn paging() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    let query = |page| SearchQuery { grade: Some("8급".to_string()), page: Some(page), per_page: Some(20), ..SearchQuery::default() };
+    let pages = (1..=3).map(|page| dic.search(&query(page)).unwrap()).collect::<Vec<_>>();
+    assert_eq!(pages[0].total, 50);
+    assert_eq!(pages.iter().map(|page| page.results.len()).collect::<Vec<_>>(), [20, 20, 10]);
+}
+
+#[test]
+fn bad_queries() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    assert!(dic.search(&SearchQuery::default()).is_err());
+    assert!(dic.search(&SearchQuery { reading: Some("가나".to_string()), ..SearchQuery::default() }).is_err());
+    assert!(dic.search(&SearchQuery { extra_strokes: Some(4), ..SearchQuery::default() }).is_err());
+}
@@ -0,0 +1,54 @@
+// 이어진 한자의 단어 나누기와 두음법칙
+// 불규칙 변환 한자사전은 남은 한자 전체가 사전 단어일 때 찾고, 나머지는 한 글자씩 변환한다.
+// 두음법칙은 뒤에 한글이나 한자가 이어지는 글자에 적용한다.
+
+use rust_web::{ConvertOptions, Dictionary, OutputMode, SpanKind};
+
+fn convert(dic: &Dictionary, text: &str) -> String {
+    dic.convert(text, &ConvertOptions::default()).render(OutputMode::Hangul)
+}
+
+#[test]
+fn multi_word_runs() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    for (text, reading) in [
+        ("年末年始", "연말연시"),
+        ("年末年始에", "연말연시에"),
+        ("大韓民國 國民", "대한민국 국민"),
+        ("勞動者", "노동자"),
+        ("來日", "내일"),
+    ] {
+        assert_eq!(convert(&dic, text), reading, "{}", text);
+    }
+}
+
+// 사전 단어가 문장 안에 따로 있거나 이어진 한자의 끝에 있으면 찾는다.
+#[test]
+fn dictionary_words() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    assert_eq!(convert(&dic, "金氏는 車庫에"), "김씨는 차고에");
+
+    let conversion = dic.convert("金氏車庫", &ConvertOptions::default());
+    assert_eq!(conversion.render(OutputMode::Hangul), "김씨차고");
+    let words = conversion.spans.iter().filter(|span| span.kind == SpanKind::Word).map(|span| span.source.as_str()).collect::<Vec<&str>>();
+    assert_eq!(words, ["車庫"]);
+}
+
+#[test]
+fn without_dueum() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    let options = ConvertOptions { dueum: false, ..ConvertOptions::default() };
+    assert_eq!(dic.convert("年末年始", &options).render(OutputMode::Hangul), "년말년시");
+}
+
+// 한자가 아닌 글자는 하나의 Text 구간으로 합치고, 읽기는 원문과 같다.
+#[test]
+fn text_spans() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    let conversion = dic.convert("오늘 學校에 간다", &ConvertOptions::default());
+    let texts = conversion.spans.iter()
+        .filter(|span| span.kind == SpanKind::Text)
+        .map(|span| (span.source.as_str(), span.reading.as_str()))
+        .collect::<Vec<(&str, &str)>>();
+    assert_eq!(texts, [("오늘 ", "오늘 "), ("에 간다", "에 간다")]);
+}
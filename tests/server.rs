@@ -0,0 +1,48 @@
+// 공통 미들웨어 (CORS, 시간 제한, 동시 처리 수 제한)
+// 미들웨어가 만든 오류 응답(408, 503)에도 CORS 헤더가 붙는지 확인한다.
+
+use std::time::Duration;
+
+use axum::body::Body;
+use axum::http::{header, Request, StatusCode};
+use axum::{routing::get, Router};
+use rust_web::server::with_common_layers;
+use rust_web::Config;
+use tower::ServiceExt;
+
+// 요청을 오래 붙잡고 있는 경로
+fn slow_app(config: &Config) -> Router {
+    let router = Router::new().route("/slow", get(|| async {
+        tokio::time::sleep(Duration::from_secs(60)).await;
+        "done"
+    }));
+    with_common_layers(router, config)
+}
+
+fn cross_origin_request(config: &Config) -> Request<Body> {
+    Request::get("/slow")
+        .header(header::ORIGIN, config.cors_allowed_origins[0])
+        .body(Body::empty())
+        .unwrap()
+}
+
+#[tokio::test]
+async fn timeout_has_cors_headers() {
+    let config = Config { request_timeout_secs: 1, ..rust_web::get_config() };
+    let response = slow_app(&config).oneshot(cross_origin_request(&config)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::REQUEST_TIMEOUT);
+    assert_eq!(response.headers()[header::ACCESS_CONTROL_ALLOW_ORIGIN], config.cors_allowed_origins[0]);
+}
+
+#[tokio::test]
+async fn shed_has_cors_headers() {
+    let config = Config { concurrency_limit: 1, ..rust_web::get_config() };
+    let app = slow_app(&config);
+    let busy = tokio::spawn(app.clone().oneshot(cross_origin_request(&config)));
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let response = app.oneshot(cross_origin_request(&config)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(response.headers()[header::ACCESS_CONTROL_ALLOW_ORIGIN], config.cors_allowed_origins[0]);
+    busy.abort();
+}
@@ -0,0 +1,48 @@
+// 빌드할 때 만든 정적 사전과 실행 중에 파싱한 사전 비교
+// 두 방법으로 읽은 사전은 같은 항목을 갖고, 같은 입력을 같은 결과로 변환해야 한다.
+
+use std::collections::HashMap;
+
+use rust_web::{convert_spans, ConvertOptions, OutputMode};
+
+#[test]
+fn same_entries() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    let (char_dic, dueum_dic, word_dic) = rust_web::load_dictionary().unwrap();
+
+    // 사전에 없는 호환용 한자와 부수 글자의 읽기도 같은 규칙으로 만든다.
+    assert_eq!(char_dic.len(), dic.char_dic.len());
+    for (key, value) in &char_dic {
+        assert_eq!(dic.char_dic.get(key), Some(value), "{}", key);
+    }
+    assert_eq!(dueum_dic.len(), dic.dueum_dic.len());
+    for (key, value) in &dueum_dic {
+        assert_eq!(dic.dueum_dic.get(key), Some(value), "{}", key);
+    }
+    assert_eq!(word_dic.len(), dic.word_dic.len());
+    for (key, value) in &word_dic {
+        assert_eq!(dic.word_dic.get(key.as_str()).copied(), Some(value.as_str()), "{}", key);
+    }
+}
+
+#[test]
+fn same_conversion() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    let (char_dic, dueum_dic, word_dic) = rust_web::load_dictionary().unwrap();
+    let no_chars: HashMap<char, char> = HashMap::new();
+    let options = ConvertOptions::default();
+
+    for text in ["大韓民國 憲法", "金氏가 車庫에서 客車를 보았다.", "樂山樂水", "勞動 歷史", "\u{2F800}\u{2F00}\u{2E9F}"] {
+        let runtime = convert_spans(text, &char_dic, &dueum_dic, &word_dic, &no_chars, &no_chars, &options);
+        assert_eq!(dic.convert(text, &options).render(OutputMode::Hangul), runtime.render(OutputMode::Hangul), "{}", text);
+    }
+}
+
+#[test]
+fn derived_readings() {
+    let (char_dic, _, _) = rust_web::load_dictionary().unwrap();
+    assert_eq!(char_dic.get(&'\u{2F00}'), Some(&'일'));  // 강희부수 ⼀
+    assert_eq!(char_dic.get(&'\u{2F800}'), char_dic.get(&'\u{4E3D}'));  // 호환용 丽
+    // 사전에 자신의 읽기가 있는 호환용 한자는 그대로 쓴다.
+    assert_eq!(char_dic.get(&'\u{F914}'), Some(&'낙'));
+}
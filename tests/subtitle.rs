@@ -0,0 +1,56 @@
+// 자막 변환 (SRT, WebVTT)
+// 번호, 시간 줄, 큐 설정, 머리말은 그대로 두고 대사만 변환하는지, 너비를 넘게 된 줄을 경고하는지 확인한다.
+
+use rust_web::subtitle::{display_width, SubtitleFormat};
+use rust_web::{ConvertOptions, OutputMode};
+
+const SRT: &str = "1\r\n00:00:01,000 --> 00:00:04,000\r\n<i>大韓民國</i>\r\n國民\r\n\r\n2\r\n00:00:05,500 --> 00:00:07,250\r\n學校\r\n";
+
+#[test]
+fn srt_timing() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    let result = dic.convert_subtitle(SRT, SubtitleFormat::Srt, &ConvertOptions::default(), 40).unwrap();
+    assert!(result.converted);
+    assert_eq!(result.subtitle, "1\r\n00:00:01,000 --> 00:00:04,000\r\n<i>대한민국</i>\r\n국민\r\n\r\n2\r\n00:00:05,500 --> 00:00:07,250\r\n학교\r\n");
+    assert!(result.warnings.is_empty());
+}
+
+#[test]
+fn vtt_header_and_settings() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    let vtt = "\u{FEFF}WEBVTT\n\nNOTE 學校\n\nintro\n00:01.000 --> 00:04.000 line:0 align:start\n<v 金氏>學校</v>\n";
+    let options = ConvertOptions { mode: OutputMode::Ruby, ..ConvertOptions::default() };
+    let result = dic.convert_subtitle(vtt, SubtitleFormat::detect(vtt), &options, 40).unwrap();
+    assert_eq!(result.format, SubtitleFormat::Vtt);
+    assert_eq!(result.subtitle,
+        "\u{FEFF}WEBVTT\n\nNOTE 學校\n\nintro\n00:01.000 --> 00:04.000 line:0 align:start\n<v 金氏><ruby>學<rt>학</rt></ruby><ruby>校<rt>교</rt></ruby></v>\n");
+}
+
+// SRT는 <ruby>를 표시할 수 없다.
+#[test]
+fn srt_ruby() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    let options = ConvertOptions { mode: OutputMode::Ruby, ..ConvertOptions::default() };
+    assert!(dic.convert_subtitle(SRT, SubtitleFormat::Srt, &options, 40).is_err());
+}
+
+// 변환하기 전에는 너비 안에 있던 줄이 넘치게 되면 경고한다.
+#[test]
+fn width_warning() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    let options = ConvertOptions { mode: OutputMode::HanjaHangul, ..ConvertOptions::default() };
+    let warnings = |max_width: usize| {
+        let result = dic.convert_subtitle(SRT, SubtitleFormat::Srt, &options, max_width).unwrap();
+        result.warnings.iter().map(|w| (w.cue, w.line, w.width, w.text.clone())).collect::<Vec<_>>()
+    };
+    assert_eq!(warnings(10), [(1, 1, 18, "<i>大韓民國(대한민국)</i>".to_string())]);
+    // 처음부터 너비를 넘던 줄(大韓民國, 8칸)은 경고하지 않는다.
+    assert_eq!(warnings(4), [(1, 2, 10, "國民(국민)".to_string()), (2, 1, 10, "學校(학교)".to_string())]);
+}
+
+#[test]
+fn width() {
+    assert_eq!(display_width("學校 ab"), 7);
+    assert_eq!(display_width("<ruby>學<rt>학</rt></ruby>"), 2);
+    assert_eq!(display_width("<c.yellow>학교</c>"), 4);
+}
@@ -0,0 +1,65 @@
+// CSV, TSV 표 변환
+// 열을 이름과 1부터 세는 번호로 지정하고, append와 report로 붙인 열이 짧은 행에서도 같은 위치에 오는지 확인한다.
+
+use rust_web::table::{TableFormat, TableOptions};
+use rust_web::{ConvertOptions, Dictionary};
+
+fn convert(dic: &Dictionary, text: &str, format: TableFormat, table: &TableOptions) -> Result<String, String> {
+    dic.convert_table(text, format, table, &ConvertOptions::default()).map(|result| result.table)
+}
+
+fn columns(columns: &[&str]) -> TableOptions {
+    TableOptions { columns: columns.iter().map(|c| c.to_string()).collect(), ..TableOptions::default() }
+}
+
+const CSV: &str = "id,name,memo\n1,金氏,學校\n2,\"大韓, 民國\",國民\n";
+
+#[test]
+fn columns_by_name_and_index() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    let expected = "id,name,memo\n1,김씨,學校\n2,\"대한, 민국\",國民\n";
+    assert_eq!(convert(&dic, CSV, TableFormat::Csv, &columns(&["name"])).unwrap(), expected);
+    assert_eq!(convert(&dic, CSV, TableFormat::Csv, &columns(&["2"])).unwrap(), expected);
+    assert_eq!(convert(&dic, CSV, TableFormat::Csv, &columns(&["name", "3"])).unwrap(), "id,name,memo\n1,김씨,학교\n2,\"대한, 민국\",국민\n");
+}
+
+// 머리글에 같은 이름이 있으면 이름으로 본다.
+#[test]
+fn numeric_header_name() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    let csv = "2,1\n學校,金氏\n";
+    assert_eq!(convert(&dic, csv, TableFormat::Csv, &columns(&["1"])).unwrap(), "2,1\n學校,김씨\n");
+}
+
+#[test]
+fn bad_columns() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    assert!(convert(&dic, CSV, TableFormat::Csv, &columns(&[])).is_err());
+    assert!(convert(&dic, CSV, TableFormat::Csv, &columns(&["phone"])).is_err());
+    assert!(convert(&dic, CSV, TableFormat::Csv, &columns(&["0"])).is_err());
+    assert!(convert(&dic, CSV, TableFormat::Csv, &columns(&["4"])).is_err());
+}
+
+// 짧은 행은 머리글의 열 수만큼 채워서 붙인 열의 위치를 맞춘다.
+#[test]
+fn append_and_report_padding() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    let csv = "name,memo,note\n金氏,學校\n\u{20000}山\n";
+    let table = TableOptions { append: true, report: true, ..columns(&["name", "memo"]) };
+    assert_eq!(convert(&dic, csv, TableFormat::Csv, &table).unwrap(),
+        "name,memo,note,name_hangul,memo_hangul,unknown_hanja\n金氏,學校,,김씨,학교,0\n\u{20000}山,,,\u{20000}산,,1\n");
+
+    // 머리글이 없으면 가장 긴 행에 맞춘다.
+    let table = TableOptions { header: false, report: true, ..columns(&["1"]) };
+    assert_eq!(convert(&dic, "金氏,a,b\n學校\n", TableFormat::Csv, &table).unwrap(), "김씨,a,b,0\n학교,,,0\n");
+}
+
+#[test]
+fn tsv_and_crlf() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    let tsv = "\u{FEFF}name\tmemo\r\n金氏\t學校\r\n";
+    let result = dic.convert_table(tsv, TableFormat::from_file_name("list.TSV"), &columns(&["memo"]), &ConvertOptions::default()).unwrap();
+    assert_eq!(result.table, "\u{FEFF}name\tmemo\r\n金氏\t학교\r\n");
+    assert_eq!(result.rows, 1);
+    assert!(result.converted);
+}
@@ -0,0 +1,49 @@
+// 간체자, 일본 신자체 -> 번체자 변환 (traditional)
+// 켰을 때만 번체자로 바꾸어 변환하고, 바꾼 한자를 mapped로 알려 주는지 확인한다.
+
+use rust_web::{ConvertOptions, Dictionary, MappedChar, Normalize, OutputMode};
+
+fn convert(dic: &Dictionary, text: &str, traditional: bool) -> String {
+    let options = ConvertOptions { traditional, ..ConvertOptions::default() };
+    dic.convert(text, &options).render(OutputMode::HanjaHangul)
+}
+
+#[test]
+fn simplified_and_shinjitai() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    assert_eq!(convert(&dic, "经济 経済 經濟", true), "经济(경제) 経済(경제) 經濟(경제)");
+    // 번체자로 바꾼 다음 단어와 읽기 규칙을 찾는다.
+    assert_eq!(convert(&dic, "数字 车间", true), "数字(숫자) 车间(찻간)");
+}
+
+// 体는 국내에서 '분'으로 읽는 글자여서 기본값(끔)에서는 바꾸지 않는다.
+#[test]
+fn off_by_default() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    assert!(!ConvertOptions::default().traditional);
+    assert_eq!(convert(&dic, "体育", false), "体育(분육)");
+    assert_eq!(convert(&dic, "体育", true), "体育(체육)");
+    assert_eq!(convert(&dic, "数字 车间", false), "数字(수자) 车间(차간)");
+    assert!(dic.convert("经济 体育", &ConvertOptions::default()).mapped().is_empty());
+}
+
+#[test]
+fn mapped() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    let options = ConvertOptions { traditional: true, ..ConvertOptions::default() };
+    let mapped = dic.convert("经济 経済 经济", &options).mapped();
+    assert_eq!(mapped, [
+        MappedChar { from: '经', to: '經' },
+        MappedChar { from: '济', to: '濟' },
+        MappedChar { from: '経', to: '經' },
+        MappedChar { from: '済', to: '濟' },
+    ]);
+}
+
+// rewrite와 함께 켜면 출력하는 한자도 번체자로 바꾼다.
+#[test]
+fn with_rewrite() {
+    let dic = rust_web::load_arc_dictionary().unwrap();
+    let options = ConvertOptions { traditional: true, normalize: Normalize::Rewrite, ..ConvertOptions::default() };
+    assert_eq!(dic.convert("经济 体育", &options).render(OutputMode::HanjaHangul), "經濟(경제) 體育(체육)");
+}